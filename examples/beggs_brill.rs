@@ -0,0 +1,40 @@
+// Compares Beggs-Brill with the Taitel-Dukler based model over a range of inclinations.
+// Run with `cargo run --example beggs_brill`.
+
+use std::error::Error;
+
+use twophase::prelude::*;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    for degree in [-10.0, -5.0, 0.0, 5.0, 10.0] {
+        let input = LineInput::builder()
+            .liquid_mass_flow(64870.6) // [kg/hr]
+            .vapor_mass_flow(21623.5) // [kg/hr]
+            .liquid_density(790.99) // [kg/m^3]
+            .vapor_density(4.581) // [kg/m^3]
+            .liquid_viscosity(0.241) // [cP]
+            .vapor_viscosity(0.0091) // [cP]
+            .surface_tension(14.78) // [dyne/cm]
+            .inside_diameter(202.72) // [mm]
+            .roughness(0.04572) // [mm]
+            .inclination(degree) // [degree]
+            .build()?;
+
+        println!("inclination {} degree", degree);
+        let lines: [&dyn TwoPhaseLine; 2] = [&Horizontal, &BeggsBrill];
+        for line in lines {
+            match line.gradient(&input) {
+                Ok(r) => println!(
+                    "  {:<42} {:<24} holdup = {:.4}, Pfric = {:.4}, Pgrav = {:.4} kgf/cm^2/100m",
+                    line.method(),
+                    r.regime.name(),
+                    r.holdup,
+                    r.Pfric,
+                    r.Pgrav
+                ),
+                Err(e) => println!("  {:<42} {}", line.method(), e),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,73 @@
+// Two-phase losses through the fittings of a line, added to the straight-run loss,
+// and the quick equivalent-length estimate of the same line.
+// Run with `cargo run --example fittings`.
+
+use std::error::Error;
+
+use twophase::prelude::*;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = LineInput::builder()
+        .liquid_mass_flow(64870.6) // [kg/hr]
+        .vapor_mass_flow(21623.5) // [kg/hr]
+        .liquid_density(790.99) // [kg/m^3]
+        .vapor_density(4.581) // [kg/m^3]
+        .liquid_viscosity(0.241) // [cP]
+        .vapor_viscosity(0.0091) // [cP]
+        .surface_tension(14.78) // [dyne/cm]
+        .pipe(PipeGeometry::from_nps("8", "40")?)
+        .build()?;
+    let length = 120.0; // straight run [m]
+    let fittings = [
+        (Fitting::Elbow90LongRadius, 6),
+        (Fitting::TeeRun, 2),
+        (Fitting::TeeBranch, 1),
+        (Fitting::GateValve, 2),
+        (Fitting::SwingCheckValve, 1),
+        (Fitting::GlobeValve, 1),
+        (
+            Fitting::Expansion {
+                pipe: PipeGeometry::from_nps("10", "40")?,
+            },
+            1,
+        ),
+    ];
+
+    for model in [FittingModel::Homogeneous, FittingModel::Separated] {
+        println!("{:?} valves and area changes", model);
+        println!(
+            "  {:<28} {:>5} {:>8} {:>8} {:>10}",
+            "fitting", "count", "K", "phi2", "dP"
+        );
+        for (fitting, count) in &fittings {
+            let r = fitting.loss(&input, model)?;
+            println!(
+                "  {:<28} {:>5} {:>8.4} {:>8.3} {:>10.5}",
+                fitting.name(),
+                count,
+                r.K,
+                r.phi2,
+                r.dP * *count as f64
+            );
+        }
+        let straight = Horizontal.gradient(&input)?.total_gradient() * length / 100.0;
+        let total = fittings_loss(&input, &fittings, model)?;
+        println!(
+            "  straight run {:.4} + fittings {:.4} = {:.4} kgf/cm^2",
+            straight,
+            total,
+            straight + total
+        );
+    }
+
+    // equivalent length: the expansion has no L/D and is left out
+    let segment = Segment::new(length, input.pipe.clone()).fittings(&fittings);
+    let r = Horizontal.gradient(&input)?;
+    println!(
+        "Equivalent length {:.1} m + straight run {:.1} m, dP {:.4} kgf/cm^2",
+        segment.fittings_length(),
+        length,
+        segment.dP(&r)
+    );
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// Compares the separated-flow frictional correlations with the mechanistic model.
+// Run with `cargo run --example frictional`.
+
+use std::error::Error;
+
+use twophase::prelude::*;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = LineInput::builder()
+        .liquid_mass_flow(64870.6) // [kg/hr]
+        .vapor_mass_flow(21623.5) // [kg/hr]
+        .liquid_density(790.99) // [kg/m^3]
+        .vapor_density(4.581) // [kg/m^3]
+        .liquid_viscosity(0.241) // [cP]
+        .vapor_viscosity(0.0091) // [cP]
+        .surface_tension(14.78) // [dyne/cm]
+        .inside_diameter(202.72) // [mm]
+        .roughness(0.04572) // [mm]
+        .build()?;
+
+    let mechanistic = Horizontal.gradient(&input)?;
+    println!(
+        "{:<24} Pfric = {:.4} kgf/cm^2/100m, holdup = {:.4} ({})",
+        Horizontal.method(),
+        mechanistic.Pfric,
+        mechanistic.holdup,
+        mechanistic.regime
+    );
+    for method in [
+        Frictional::LockhartMartinelli,
+        Frictional::ChisholmB,
+        Frictional::Friedel,
+        Frictional::MullerSteinhagenHeck,
+    ] {
+        let r = method.gradient(&input)?;
+        let holdup = r.holdup.map_or(String::from("-"), |h| format!("{:.4}", h));
+        println!(
+            "{:<24} Pfric = {:.4} kgf/cm^2/100m, holdup = {}, phi^2 = {:.3}",
+            method.name(),
+            r.Pfric,
+            holdup,
+            r.phi2
+        );
+    }
+    Ok(())
+}
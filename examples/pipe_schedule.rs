@@ -0,0 +1,50 @@
+// Looks up standard pipe sizes and runs one line case in each pipe material.
+// Run with `cargo run --example pipe_schedule`.
+
+use std::error::Error;
+
+use twophase::prelude::*;
+use twophase::schedule;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    println!(
+        "{:>6} {:>6} {:>10} {:>10} {:>10}",
+        "NPS", "SCH", "OD", "WT", "ID"
+    );
+    for size in schedule::schedules("8") {
+        println!(
+            "{:>6} {:>6} {:>10.2} {:>10.2} {:>10.2}",
+            size.nps,
+            size.schedule,
+            size.OD * 1000.0,
+            size.WT * 1000.0,
+            size.ID() * 1000.0
+        );
+    }
+
+    for material in [
+        Material::CarbonSteel,
+        Material::StainlessSteel,
+        Material::GRE,
+        Material::Lined,
+    ] {
+        let input = LineInput::builder()
+            .liquid_mass_flow(64870.6) // [kg/hr]
+            .vapor_mass_flow(21623.5) // [kg/hr]
+            .liquid_density(790.99) // [kg/m^3]
+            .vapor_density(4.581) // [kg/m^3]
+            .liquid_viscosity(0.241) // [cP]
+            .vapor_viscosity(0.0091) // [cP]
+            .surface_tension(14.78) // [dyne/cm]
+            .pipe(PipeGeometry::from_nps("8", "SCH 40")?.with_material(material))
+            .build()?;
+        let r = Horizontal.gradient(&input)?;
+        println!(
+            "8\" SCH 40 {:<16} Pfric = {:.4} kgf/cm^2/100m ({})",
+            material.name(),
+            r.Pfric,
+            r.regime
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// Marches a line case along a three segment pipeline, with the vapor density
+// following the pressure as an ideal gas.
+// Run with `cargo run --example pipeline`.
+
+use std::error::Error;
+
+use twophase::prelude::*;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = LineInput::builder()
+        .liquid_mass_flow(64870.6) // [kg/hr]
+        .vapor_mass_flow(21623.5) // [kg/hr]
+        .liquid_density(790.99) // [kg/m^3]
+        .vapor_density(4.581) // [kg/m^3] at the inlet pressure
+        .liquid_viscosity(0.241) // [cP]
+        .vapor_viscosity(0.0091) // [cP]
+        .surface_tension(14.78) // [dyne/cm]
+        .inside_diameter(202.72) // [mm], replaced by each segment
+        .build()?;
+    let pipe = PipeGeometry::from_nps("10", "40")?;
+    let pipeline = Pipeline::new(vec![
+        Segment::new(500.0, pipe.clone()),             // [m] horizontal
+        Segment::with_rise(200.0, pipe.clone(), 5.0)?, // [m] 5 m uphill
+        Segment::with_rise(300.0, pipe, -15.0)?,       // [m] 15 m downhill
+    ])
+    .step(50.0) // [m]
+    .gas_law(GasLaw::Ideal);
+
+    let profile = pipeline.march(&input, 15.0)?; // inlet [kgf/cm^2 abs]
+    println!(
+        "{:>8} {:>8} {:>10} {:>8} {:>8} {:>10}  regime",
+        "L [m]", "z [m]", "P", "LoG", "holdup", "gradient"
+    );
+    for p in &profile.points {
+        println!(
+            "{:>8.1} {:>8.2} {:>10.4} {:>8.3} {:>8.4} {:>10.4}  {}",
+            p.distance,
+            p.elevation,
+            p.pressure,
+            p.LoG,
+            p.result.holdup,
+            p.gradient,
+            p.result.regime
+        );
+    }
+    println!(
+        "outlet {:.4} kgf/cm^2 abs, dP {:.4} kgf/cm^2",
+        profile.outlet_pressure(),
+        profile.dP()
+    );
+    Ok(())
+}
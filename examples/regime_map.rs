@@ -0,0 +1,33 @@
+// Writes the regime map of a horizontal line as regime_map.csv and regime_map.svg.
+// Run with `cargo run --example regime_map`.
+
+use std::error::Error;
+use std::fs;
+
+use twophase::prelude::*;
+use twophase::{regime_map, MapOptions};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = LineInput::builder()
+        .liquid_mass_flow(64870.6) // [kg/hr]
+        .vapor_mass_flow(21623.5) // [kg/hr]
+        .liquid_density(790.99) // [kg/m^3]
+        .vapor_density(4.581) // [kg/m^3]
+        .liquid_viscosity(0.241) // [cP]
+        .vapor_viscosity(0.0091) // [cP]
+        .surface_tension(14.78) // [dyne/cm]
+        .inside_diameter(202.72) // [mm]
+        .build()?;
+
+    let map = regime_map(&Horizontal, &input, MapOptions::default())?;
+    println!(
+        "operating point UGS = {:.4} m/s, ULS = {:.4} m/s : {:?}",
+        map.operating.0, map.operating.1, map.operating_regime
+    );
+    for b in &map.boundaries {
+        println!("{} / {} : {} points", b.from, b.to, b.points.len());
+    }
+    fs::write("regime_map.csv", map.to_csv())?;
+    fs::write("regime_map.svg", map.to_svg())?;
+    Ok(())
+}
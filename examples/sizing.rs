@@ -0,0 +1,67 @@
+// Sizes a horizontal line: the smallest schedule 40 pipe with an acceptable
+// pressure gradient and erosion factor and no slug flow.
+// Run with `cargo run --example sizing`.
+
+use std::error::Error;
+
+use twophase::prelude::*;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = LineInput::builder()
+        .liquid_mass_flow(64870.6) // [kg/hr]
+        .vapor_mass_flow(21623.5) // [kg/hr]
+        .liquid_density(790.99) // [kg/m^3]
+        .vapor_density(4.581) // [kg/m^3]
+        .liquid_viscosity(0.241) // [cP]
+        .vapor_viscosity(0.0091) // [cP]
+        .surface_tension(14.78) // [dyne/cm]
+        .inside_diameter(202.72) // [mm], replaced by each size
+        .build()?;
+    let criteria = SizingCriteria {
+        schedule: "40",
+        max_Pfric: Some(0.5), // [kgf/cm^2/100m]
+        max_Ef: Some(1.0),    // [-]
+        max_velocity: None,
+        forbidden: vec![FlowPattern::Intermittent],
+    };
+
+    let sizing = size_line(&Horizontal, &input, &criteria);
+    println!("{}, schedule {}", sizing.method, criteria.schedule);
+    println!(
+        "  {:>6} {:>8} {:>8} {:>8} {:<32} result",
+        "NPS", "ID", "Pfr+Pacc", "Ef", "regime"
+    );
+    for case in &sizing.cases {
+        let verdict = if case.accepted() {
+            String::from("ok")
+        } else {
+            let reasons: Vec<String> = case.rejections.iter().map(|r| r.to_string()).collect();
+            reasons.join("; ")
+        };
+        match &case.result {
+            Some(r) => println!(
+                "  {:>6} {:>8.2} {:>8.4} {:>8.4} {:<32} {}",
+                case.size.nps,
+                case.size.ID() * 1000.0,
+                r.Pfric + r.Pacc,
+                r.Ef,
+                r.regime.name(),
+                verdict
+            ),
+            None => println!(
+                "  {:>6} {:>8.2} {:>8} {:>8} {:<32} {}",
+                case.size.nps,
+                case.size.ID() * 1000.0,
+                "-",
+                "-",
+                "-",
+                verdict
+            ),
+        }
+    }
+    match sizing.selected() {
+        Some(case) => println!("selected NPS {} SCH {}", case.size.nps, case.size.schedule),
+        None => println!("no size meets the criteria"),
+    }
+    Ok(())
+}
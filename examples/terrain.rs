@@ -0,0 +1,59 @@
+// Marches a line case over a terrain flowline read from survey points, with and
+// without the downhill elevation recovery.
+// Run with `cargo run --example terrain`.
+
+use std::error::Error;
+
+use twophase::prelude::*;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = LineInput::builder()
+        .liquid_mass_flow(64870.6) // [kg/hr]
+        .vapor_mass_flow(21623.5) // [kg/hr]
+        .liquid_density(790.99) // [kg/m^3]
+        .vapor_density(4.581) // [kg/m^3] at the inlet pressure
+        .liquid_viscosity(0.241) // [cP]
+        .vapor_viscosity(0.0091) // [cP]
+        .surface_tension(14.78) // [dyne/cm]
+        .inside_diameter(254.51) // [mm], replaced by the survey pipe
+        .build()?;
+    let survey = Survey::read_csv(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/terrain.csv"))?;
+    let pipe = PipeGeometry::from_nps("10", "40")?;
+
+    for recovery in [1.0, 0.0] {
+        let pipeline = survey
+            .pipeline(&pipe)?
+            .step(100.0) // [m]
+            .gas_law(GasLaw::Ideal)
+            .recovery(recovery);
+        let profile = pipeline.march(&input, 30.0)?; // inlet [kgf/cm^2 abs]
+        println!("downhill recovery {}", recovery);
+        println!(
+            "  {:>3} {:>8} {:>8} {:>8} {:>8} {:>8}  regime",
+            "leg", "degree", "P out", "holdup", "Pfric", "Pelev"
+        );
+        for (k, segment) in pipeline.segments.iter().enumerate() {
+            if let Some(p) = profile.points.iter().rev().find(|p| p.segment == k) {
+                println!(
+                    "  {:>3} {:>8.2} {:>8.4} {:>8.4} {:>8.4} {:>8.4}  {}",
+                    k + 1,
+                    segment.pipe.degree,
+                    p.pressure,
+                    p.result.holdup,
+                    p.Pfric,
+                    p.Pelev,
+                    p.result.regime
+                );
+            }
+        }
+        println!(
+            "  friction {:.4} + acceleration {:.4} + uphill {:.4} - downhill {:.4} = dP {:.4} kgf/cm^2",
+            profile.friction_loss(),
+            profile.acceleration_loss(),
+            profile.uphill_loss(),
+            profile.downhill_recovery(),
+            profile.dP()
+        );
+    }
+    Ok(())
+}
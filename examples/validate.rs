@@ -0,0 +1,280 @@
+#![allow(non_snake_case)]
+
+// Validation cases for the Horizontal, Vertical-Up and Vertical-Down models.
+// Run with `cargo run --example validate`.
+
+use std::error::Error;
+
+use twophase::prelude::*;
+
+fn print_result(r: &LineResult) {
+    println!("flow regime << {} >>", r.regime);
+    if let Some(b) = &r.blend {
+        println!("blended with << {} >>, weight {:.3}", b.neighbour, b.weight);
+    }
+    if let Some(fb) = &r.fallback {
+        println!(
+            "{} failed ({}), homogeneous model used",
+            fb.method, fb.error
+        );
+    }
+    println!("Two-Phase Density (kg/m^3) = {:.4}", r.density);
+    println!("Liquid Volume Fraction (-) = {:.4}", r.holdup);
+    println!("Two-Phase Velocity (m/sec) = {:.4}", r.velocity);
+    match &r.detail {
+        Some(RegimeDetail::Slug {
+            LoLS,
+            LoSU,
+            Us,
+            Ls,
+            Lu,
+            Le,
+        }) => {
+            println!("Liquid Slug Density (kg/m^3) = {:.4}", LoLS);
+            println!("Two-Phase Slug Unit Density (kg/m^3) = {:.4}", LoSU);
+            println!("Liquid Slug Velocity (m/sec) = {:.4}", Us);
+            if let Some(Ls) = Ls {
+                println!("Liquid Slug Length (m) = {:.4}", Ls);
+            }
+            if let Some(Lu) = Lu {
+                println!("Slug Unit Length (Liq + Vap) (m) = {:.4}", Lu);
+            }
+            if let Some(Le) = Le {
+                println!("Stabilizes to Slug Flow in x m = {:.4}", Le);
+            }
+        }
+        Some(RegimeDetail::Stratified { depth, velL, velG }) => {
+            println!("Liquid Depth-BOP (m) = {:.4}", depth);
+            println!("Liquid Velocity (m/sec) = {:.4}", velL);
+            println!("Vapor Velocity (m/sec) = {:.4}", velG);
+        }
+        Some(RegimeDetail::PhaseVelocities { velL, velG }) => {
+            println!("Liquid Velocity (m/sec) = {:.4}", velL);
+            println!("Vapor Velocity (m/sec) = {:.4}", velG);
+        }
+        None => {}
+    }
+    println!("1.0 Velocity Head (kgf/cm^2) = {:.4}", r.Head);
+    println!("Frictional Pressure Loss (kgf/cm^2/100m) = {:.4}", r.Pfric);
+    println!("Elevation Head Loss (kgf/cm^2/100m) = {:.4}", r.Pgrav);
+    println!("Acceleration Loss (kgf/cm^2/100m) = {:.4}", r.Pacc);
+    println!(
+        "Total Pressure Gradient (kgf/cm^2/100m) = {:.4}",
+        r.total_gradient()
+    );
+    println!("Erosion Factor (-) = {:.3}", r.Ef);
+    println!("if Φ ≤ 1 : No Erosion, Φ > 1, Erosion occurred");
+}
+
+fn vertical_up_validate() -> Result<(), Box<dyn Error>> {
+    //Region Test data for Annular Flow
+    let input = LineInput::builder()
+        // Liquid data
+        .liquid_mass_flow(72036.365) // [kg/hr]
+        .liquid_density(379.63758) // [kg/m^3]
+        .liquid_viscosity(0.054) // [cP]
+        .surface_tension(40.0) // [dyne/cm]
+        // Vapor data
+        .vapor_mass_flow(78722.747) // [kg/hr]
+        .vapor_density(75.286778) // [kg/m^3]
+        .vapor_viscosity(0.011) // [cP]
+        // Misc. data
+        .inside_diameter(304.8) // [mm], 12.0 in
+        .inclination(-90.0) // [degree]
+        .roughness(0.04572) // [mm]
+        .safety_factor(1.0) // [-]
+        .build()?;
+
+    let p1 = VerticalUp.gradient(&input)?;
+    print_result(&p1);
+    // EndRegion
+
+    // Region Test data for Bubble Flow
+    let input = LineInput::builder()
+        // Liquid data
+        .liquid_mass_flow(100000.0) // [kg/hr]
+        .liquid_density(500.0) // [kg/m^3]
+        .liquid_viscosity(1.0) // [cP]
+        .surface_tension(30.0) // [dyne/cm]
+        // Vapor data
+        .vapor_mass_flow(50.0) // [kg/hr]
+        .vapor_density(2.0) // [kg/m^3]
+        .vapor_viscosity(0.01) // [cP]
+        // Misc. data
+        .inside_diameter(154.051) // [mm], 6.065 in
+        .inclination(-90.0) // [degree]
+        .roughness(0.046) // [mm]
+        .safety_factor(1.0) // [-]
+        .build()?;
+
+    let p2 = VerticalUp.gradient(&input)?;
+    print_result(&p2);
+    //EndRegion
+
+    // Region Test data for Slug Model
+    let input = LineInput::builder()
+        // Liquid data
+        .liquid_mass_flow(90718.0) // [kg/hr]
+        .liquid_density(640.73852) // [kg/m^3]
+        .liquid_viscosity(0.3) // [cP]
+        .surface_tension(20.0) // [dyne/cm]
+        // Vapor data
+        .vapor_mass_flow(1814.36) // [kg/hr]
+        .vapor_density(8.00923) // [kg/m^3]
+        .vapor_viscosity(0.01) // [cP]
+        // Misc. data
+        .inside_diameter(154.051) // [mm], 6.065 in
+        .inclination(-90.0) // [degree]
+        .roughness(0.04572) // [mm]
+        .safety_factor(1.0) // [-]
+        .build()?;
+
+    let p3 = VerticalUp.gradient(&input)?;
+    print_result(&p3);
+    //EndRegion
+    Ok(())
+}
+
+fn horizontal_validate() -> Result<(), Box<dyn Error>> {
+    //Region Test data for Annular-Dispersed Flow (Similarity Model)
+    let input = LineInput::builder()
+        // Liquid data
+        .liquid_mass_flow(64870.62744) // [kg/hr]
+        .liquid_density(790.9917) // [kg/m^3]
+        .liquid_viscosity(0.241) // [cP]
+        .surface_tension(14.78) // [dyne/cm]
+        // Vapor data
+        .vapor_mass_flow(21623.54248) // [kg/hr]
+        .vapor_density(4.58128) // [kg/m^3]
+        .vapor_viscosity(0.0091) // [cP]
+        // Misc. data
+        .inside_diameter(202.7174) // [mm], 7.981 in
+        .inclination(0.0) // [degree]
+        .roughness(0.04572) // [mm]
+        .safety_factor(1.0) // [-]
+        .build()?;
+
+    let p1 = Horizontal.gradient(&input)?;
+    print_result(&p1);
+    // EndRegion
+
+    //Region Test data for Stratified Wavy Flow (Stratified Model)
+    let input = LineInput::builder()
+        // Liquid data
+        .liquid_mass_flow(64870.62744) // [kg/hr]
+        .liquid_density(790.9917) // [kg/m^3]
+        .liquid_viscosity(0.241) // [cP]
+        .surface_tension(14.78) // [dyne/cm]
+        // Vapor data
+        .vapor_mass_flow(21623.54248) // [kg/hr]
+        .vapor_density(4.58128) // [kg/m^3]
+        .vapor_viscosity(0.0091) // [cP]
+        // Misc. data
+        .inside_diameter(590.55) // [mm], 23.25 in
+        .inclination(0.0) // [degree]
+        .roughness(0.04572) // [mm]
+        .safety_factor(1.0) // [-]
+        .build()?;
+
+    let p2 = Horizontal.gradient(&input)?;
+    print_result(&p2);
+    // EndRegion
+
+    //Region Test data for Intermittent-Slug Flow (Slug Model)
+    let input = LineInput::builder()
+        // Liquid data
+        .liquid_mass_flow(116604.0) // [kg/hr]
+        .liquid_density(803.0) // [kg/m^3]
+        .liquid_viscosity(0.45) // [cP]
+        .surface_tension(9.3) // [dyne/cm]
+        // Vapor data
+        .vapor_mass_flow(1896.0) // [kg/hr]
+        .vapor_density(8.49) // [kg/m^3]
+        .vapor_viscosity(0.02) // [cP]
+        // Misc. data
+        .inside_diameter(202.7174) // [mm], 7.981 in
+        .inclination(0.0) // [degree]
+        .roughness(0.046) // [mm]
+        .safety_factor(1.0) // [-]
+        .build()?;
+
+    let p3 = Horizontal.gradient(&input)?;
+    print_result(&p3);
+    // EndRegion
+    Ok(())
+}
+
+fn vertical_down_validate() -> Result<(), Box<dyn Error>> {
+    //Region Test data for Annular Flow
+    let input = LineInput::builder()
+        // Liquid data
+        .liquid_mass_flow(21937.88) // [kg/hr]
+        .liquid_density(962.0689) // [kg/m^3]
+        .liquid_viscosity(0.511) // [cP]
+        .surface_tension(30.3) // [dyne/cm]
+        // Vapor data
+        .vapor_mass_flow(376.93329) // [kg/hr]
+        .vapor_density(0.9931447) // [kg/m^3]
+        .vapor_viscosity(0.01) // [cP]
+        // Misc. data
+        .inside_diameter(387.35) // [mm], 15.25 in
+        .inclination(90.0) // [degree]
+        .roughness(0.04572) // [mm]
+        .safety_factor(1.0) // [-]
+        .build()?;
+
+    let p1 = VerticalDown.gradient(&input)?;
+    print_result(&p1);
+    // EndRegion
+
+    //Region Test data for Dispersed Bubble Flow
+    let input = LineInput::builder()
+        // Liquid data
+        .liquid_mass_flow(4536.0) // [kg/hr]
+        .liquid_density(801.0) // [kg/m^3]
+        .liquid_viscosity(0.6) // [cP]
+        .surface_tension(10.0) // [dyne/cm]
+        // Vapor data
+        .vapor_mass_flow(0.4536) // [kg/hr]
+        .vapor_density(8.0) // [kg/m^3]
+        .vapor_viscosity(0.01) // [cP]
+        // Misc. data
+        .inside_diameter(26.6446) // [mm], 1.049 in
+        .inclination(90.0) // [degree]
+        .roughness(0.046) // [mm]
+        .safety_factor(1.0) // [-]
+        .build()?;
+
+    let p2 = VerticalDown.gradient(&input)?;
+    print_result(&p2);
+    // EndRegion
+
+    //Region Test data for Slug Flow
+    let input = LineInput::builder()
+        // Liquid data
+        .liquid_mass_flow(12000.0) // [kg/hr]
+        .liquid_density(801.0) // [kg/m^3]
+        .liquid_viscosity(0.6) // [cP]
+        .surface_tension(10.0) // [dyne/cm]
+        // Vapor data
+        .vapor_mass_flow(450.0) // [kg/hr]
+        .vapor_density(8.0) // [kg/m^3]
+        .vapor_viscosity(0.01) // [cP]
+        // Misc. data
+        .inside_diameter(26.6446) // [mm], 1.049 in
+        .inclination(90.0) // [degree]
+        .roughness(0.046) // [mm]
+        .safety_factor(1.0) // [-]
+        .build()?;
+
+    let p3 = VerticalDown.gradient(&input)?;
+    print_result(&p3);
+    // EndRegion
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    vertical_up_validate()?;
+    horizontal_validate()?;
+    vertical_down_validate()
+}
@@ -0,0 +1,68 @@
+// Runs the vertical upflow validation cases through every vertical upflow method.
+// Run with `cargo run --example vertical_up_methods`.
+
+use std::error::Error;
+
+use twophase::hagedorn_brown::ATMOSPHERE;
+use twophase::prelude::*;
+
+// the vertical upflow validation cases: (name, WL [kg/hr], LoL [kg/m^3], muL [cP],
+// ST [dyne/cm], WG [kg/hr], LoG [kg/m^3], muG [cP], ID [mm])
+const CASES: [(&str, [f64; 8]); 3] = [
+    (
+        "annular",
+        [
+            72036.365, 379.63758, 0.054, 40.0, 78722.747, 75.286778, 0.011, 304.8,
+        ],
+    ),
+    (
+        "bubble",
+        [100000.0, 500.0, 1.0, 30.0, 50.0, 2.0, 0.01, 154.051],
+    ),
+    (
+        "slug",
+        [
+            90718.0, 640.73852, 0.3, 20.0, 1814.36, 8.00923, 0.01, 154.051,
+        ],
+    ),
+];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // the cases give no line pressure, the holdup chart is read at one atmosphere
+    let hagedorn_brown = HagedornBrown::new(ATMOSPHERE);
+    let methods: [&dyn TwoPhaseLine; 4] = [&VerticalUp, &hagedorn_brown, &DunsRos, &Ansari];
+
+    for (name, c) in CASES {
+        let input = LineInput::builder()
+            .liquid_mass_flow(c[0]) // [kg/hr]
+            .liquid_density(c[1]) // [kg/m^3]
+            .liquid_viscosity(c[2]) // [cP]
+            .surface_tension(c[3]) // [dyne/cm]
+            .vapor_mass_flow(c[4]) // [kg/hr]
+            .vapor_density(c[5]) // [kg/m^3]
+            .vapor_viscosity(c[6]) // [cP]
+            .inside_diameter(c[7]) // [mm]
+            .inclination(-90.0) // [degree]
+            .roughness(0.04572) // [mm]
+            .build()?;
+        println!("{} case", name);
+        println!(
+            "  {:<48} {:<42} {:>8} {:>10} {:>10}",
+            "method", "regime", "holdup", "Pfric", "Pgrav"
+        );
+        for line in methods {
+            match line.gradient(&input) {
+                Ok(r) => println!(
+                    "  {:<48} {:<42} {:>8.4} {:>10.4} {:>10.4}",
+                    line.method(),
+                    r.regime.name(),
+                    r.holdup,
+                    r.Pfric,
+                    r.Pgrav
+                ),
+                Err(e) => println!("  {:<48} {}", line.method(), e),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,312 @@
+#![allow(non_snake_case)]
+
+// Ansari, Sylvester, Sarica, Shoham and Brill (1994), "A comprehensive mechanistic
+// model for upward two-phase flow in wellbores", SPE Production & Facilities 9, 143-151.
+//
+// Transitions, checked in this order:
+//   annular          : UGS above the Taitel et al. (1980) droplet lift velocity and a
+//                      film too thin to bridge the pipe (Barnea 1986, HLF < 0.12)
+//   dispersed bubble : Barnea (1986) bubble break-up, gas fraction up to 0.76
+//   bubble           : Taitel et al. (1980) void fraction 0.25, large diameters only
+//   otherwise        : slug
+// Models: Zuber-Findlay bubble rise, no-slip dispersed bubble, Sylvester (1987) slug
+// body holdup with a falling film in the Taylor bubble, and the annular film /
+// entrained core balance. The map and models take the pipe as vertical; only the
+// elevation term follows the inclination.
+
+use crate::error::TwoPhaseError;
+use crate::input::LineInput;
+use crate::result::{LineResult, RegimeDetail};
+use crate::twoline::{Regime, RegimeReport, TwoPhaseLine};
+
+const G: f64 = 9.81; // gravity accelerator [m/s^2]
+const GC: f64 = 9.8; // gravity constant [kg-m/kgf-s^2]
+
+const ALFA_SLUG: f64 = 0.25; // void fraction at the bubble / slug transition
+const ALFA_MAX: f64 = 0.76; // maximum void fraction of dispersed bubble flow
+const HLF_MAX: f64 = 0.12; // half of the slug liquid holdup 0.24, Barnea (1986)
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Ansari;
+
+// annular film and core, from the film thickness balance
+struct Annular {
+    delta: f64,  // film thickness / ID [-]
+    LandaC: f64, // no-slip liquid fraction of the core [-]
+    LoC: f64,    // core density [kg/m^3]
+    Pfric: f64,  // core frictional gradient [kgf/cm^2/100m], without the safety factor
+}
+
+impl Ansari {
+    // bubble rise velocity in an infinite medium, Harmathy (1960) [m/s]
+    fn U0(&self, input: &LineInput) -> f64 {
+        let f = &input.fluid;
+        let sigma = f.ST * GC; // surface tension [N/m]
+        1.53 * (G * sigma * (f.LoL - f.LoG) / f.LoL.powi(2)).powf(0.25)
+    }
+
+    fn annular(&self, input: &LineInput) -> Result<Annular, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let sigma = f.ST * GC; // surface tension [N/m]
+        let (UGS, ULS) = input.superficial_velocities();
+
+        // entrainment, Wallis (1969)
+        let Vcrit = 10000.0 * UGS * f.muG / sigma * (f.LoG / f.LoL).sqrt();
+        let FE = (1.0 - (-0.125 * (Vcrit - 1.5)).exp()).clamp(0.0, 1.0);
+
+        // core with the entrained droplets at no slip
+        let UC = UGS + FE * ULS; // core superficial velocity [m/s]
+        let LandaC = FE * ULS / UC;
+        let LoC = f.LoL * LandaC + f.LoG * (1.0 - LandaC);
+        let muC = f.muL * LandaC + f.muG * (1.0 - LandaC);
+        let gradient = |fF: f64, Lo: f64, U: f64| {
+            4.0 * fF * Lo * U.powi(2) / (2.0 * G * p.ID) / 10000.0 * 100.0
+        };
+        let dPSC = gradient(input.fanning(LoC * UC * p.ID / muC)?, LoC, UC);
+        let dPSL = gradient(input.fanning(f.LoL * ULS * p.ID / f.muL)?, f.LoL, ULS);
+        let fF = input.fanning(f.LoL * ULS * (1.0 - FE) * p.ID / f.muL)?;
+        let fSL = input.fanning(f.LoL * ULS * p.ID / f.muL)?;
+        let dPF = (1.0 - FE).powi(2) * fF / fSL * dPSL; // film superficial gradient
+
+        let XM2 = dPF / dPSC;
+        let YM = (f.LoL - LoC) / 10000.0 * 100.0 / dPSC;
+        let Z = |d: f64| {
+            if FE > 0.9 {
+                1.0 + 300.0 * d
+            } else {
+                1.0 + 24.0 * (f.LoL / f.LoG).powf(1.0 / 3.0) * d
+            }
+        };
+        let delta = input
+            .solver
+            .brent(
+                "Ansari Annular: delta",
+                |d: f64| {
+                    let a = 4.0 * d * (1.0 - d);
+                    YM - Z(d) / (a * (1.0 - a).powf(2.5)) + XM2 / a.powi(3)
+                },
+                1e-9,
+                0.5 - 1e-9,
+            )?
+            .root;
+        Ok(Annular {
+            delta,
+            LandaC,
+            LoC,
+            Pfric: Z(delta) / (1.0 - 2.0 * delta).powi(5) * dPSC,
+        })
+    }
+
+    fn AnnularModel(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let Landa = ULS / UM; // no-slip holdup [-]
+        let a = self.annular(input)?;
+        let core = (1.0 - 2.0 * a.delta).powi(2); // core area fraction [-]
+        let HL = (1.0 - core) + core * a.LandaC;
+        let density = f.LoL * HL + f.LoG * (1.0 - HL);
+        let LoNS = f.LoL * Landa + f.LoG * (1.0 - Landa); // no-slip density [kg/m^3]
+        Ok(LineResult {
+            regime,
+            holdup: HL,
+            density,
+            velocity: UM,
+            Pfric: a.Pfric * input.SF,
+            Pgrav: a.LoC * input.pipe.rise_per_length() / 10000.0 * 100.0, // the film is held by the interfacial shear
+            Pacc: 0.0,
+            Head: LoNS * UM.powi(2) / (2.0 * G) / 10000.0,
+            Ef: (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0, // must transfer to imperial unit
+            detail: Some(RegimeDetail::phase_velocities(ULS, UGS, HL)),
+            blend: None,
+            fallback: None,
+        })
+    }
+
+    fn BubbleModel(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let Landa = ULS / UM; // no-slip holdup [-]
+        let U0 = self.U0(input);
+
+        let HL = match regime {
+            Regime::VerticalUpBubbleFlow(..) => {
+                // UGS / (1 - HL) = 1.2 UM + U0 HL^0.5
+                input
+                    .solver
+                    .brent(
+                        "Ansari Bubble: HL",
+                        |H: f64| UGS / (1.0 - H) - 1.2 * UM - U0 * H.sqrt(),
+                        Landa.min(1.0 - 1e-9),
+                        1.0 - 1e-9,
+                    )?
+                    .root
+            }
+            _ => Landa,
+        };
+
+        let density = f.LoL * HL + f.LoG * (1.0 - HL);
+        let LoNS = f.LoL * Landa + f.LoG * (1.0 - Landa); // no-slip density [kg/m^3]
+        let muNS = f.muL * Landa + f.muG * (1.0 - Landa); // no-slip viscosity [kg/m-s]
+        let fTP = input.fanning(LoNS * UM * p.ID / muNS)? * 4.0;
+        let Pfric = fTP * density * UM.powi(2) / (2.0 * G * p.ID) / 10000.0 * 100.0 * input.SF;
+        Ok(LineResult {
+            regime,
+            holdup: HL,
+            density,
+            velocity: UM,
+            Pfric,
+            Pgrav: density * p.rise_per_length() / 10000.0 * 100.0,
+            Pacc: 0.0,
+            Head: LoNS * UM.powi(2) / (2.0 * G) / 10000.0,
+            Ef: (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0, // must transfer to imperial unit
+            detail: Some(RegimeDetail::phase_velocities(ULS, UGS, HL)),
+            blend: None,
+            fallback: None,
+        })
+    }
+
+    fn SlugModel(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let Landa = ULS / UM; // no-slip holdup [-]
+
+        // liquid slug body, Sylvester (1987)
+        let HGLS = UGS / (0.425 + 2.65 * UM);
+        let HLLS = 1.0 - HGLS;
+        let UTB = 1.2 * UM + 0.35 * (G * p.ID * (f.LoL - f.LoG) / f.LoL).sqrt(); // Taylor bubble [m/s]
+        let UGLS = 1.2 * UM + self.U0(input) * HLLS.sqrt(); // gas in the slug body [m/s]
+
+        // falling film around the Taylor bubble, ULTB = 9.916 (g D (1 - HGTB^0.5))^0.5
+        let ULTB = |H: f64| 9.916 * (G * p.ID * (1.0 - (1.0 - H).sqrt())).sqrt();
+        let A = HGLS * (UTB - UGLS) + UM;
+        let HLTB = input
+            .solver
+            .brent(
+                "Ansari Slug: HLTB",
+                |H: f64| ULTB(H) * H - UTB * (1.0 - H) + A,
+                1e-9,
+                1.0 - 1e-9,
+            )?
+            .root;
+
+        // Taylor bubble fraction of the slug unit from the liquid balance
+        let ULLS = (UM - UGLS * HGLS) / HLLS;
+        let beta = (ULLS * HLLS - ULS) / (ULLS * HLLS + ULTB(HLTB) * HLTB);
+        TwoPhaseError::check_range("Ansari Slug: beta", beta, 0.0, 1.0)?;
+
+        let LoLS = f.LoL * HLLS + f.LoG * HGLS; // Liquid Slug Density [kg/m^3]
+        let LoSU = (1.0 - beta) * LoLS + beta * f.LoG; // the film weight is neglected
+        let HL = (1.0 - beta) * HLLS + beta * HLTB;
+        let density = f.LoL * HL + f.LoG * (1.0 - HL);
+        let fLS = input.fanning(f.LoL * UM * p.ID / f.muL)? * 4.0;
+        let Pfric =
+            (1.0 - beta) * fLS * LoLS * UM.powi(2) / (2.0 * G * p.ID) / 10000.0 * 100.0 * input.SF;
+        let LoNS = f.LoL * Landa + f.LoG * (1.0 - Landa); // no-slip density [kg/m^3]
+        let Ls = 30.0 * p.ID; // Liquid Slug Length [m]
+        Ok(LineResult {
+            regime,
+            holdup: HL,
+            density,
+            velocity: UM,
+            Pfric,
+            Pgrav: LoSU * p.rise_per_length() / 10000.0 * 100.0,
+            Pacc: 0.0,
+            Head: LoNS * UM.powi(2) / (2.0 * G) / 10000.0,
+            Ef: (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0, // must transfer to imperial unit
+            detail: Some(RegimeDetail::Slug {
+                LoLS,
+                LoSU,
+                Us: UM,
+                Ls: Some(Ls),
+                Lu: Some(Ls / (1.0 - beta)),
+                Le: None,
+            }),
+            blend: None,
+            fallback: None,
+        })
+    }
+
+    // regime from the transition ratios, in the order given to RegimeReport::new
+    fn classify(r: &[f64]) -> Regime {
+        let (ugsa, hlf, dmax, alfa, dcrit, ugsb) = (r[0], r[1], r[2], r[3], r[4], r[5]);
+        if ugsa > 1.0 && hlf < 1.0 {
+            Regime::VerticalUpAnnularFlow(String::from("Vertical Up Annular Flow"))
+        } else if dmax > 1.0 && alfa <= 1.0 {
+            Regime::VerticalUpFinelyDispersedBubbleFlow(String::from(
+                "Vertical Up Finely Dispersed Bubble Flow",
+            ))
+        } else if dcrit < 1.0 && ugsb < 1.0 {
+            Regime::VerticalUpBubbleFlow(String::from("Vertical Up Bubble Flow"))
+        } else {
+            Regime::VerticalUpSlugAndChurnFlow(String::from("Vertical Up Slug and Churn Flow"))
+        }
+    }
+}
+
+impl TwoPhaseLine for Ansari {
+    fn method(&self) -> &'static str {
+        "Ansari (1994) mechanistic vertical upflow"
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let sigma = f.ST * GC; // surface tension [N/m]
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let U0 = self.U0(input);
+
+        // annular: droplet lift, then film bridging
+        let UGSA = 3.1 * (G * sigma * (f.LoL - f.LoG) / f.LoG.powi(2)).powf(0.25);
+        let HLF = match self.annular(input) {
+            Ok(a) => 4.0 * a.delta * (1.0 - a.delta),
+            Err(TwoPhaseError::NotBracketed { .. }) => f64::INFINITY,
+            Err(e) => return Err(e),
+        };
+
+        // dispersed bubble: bubble break-up against the size of a stable bubble
+        let fM = input.fanning(f.LoL * UM * p.ID / f.muL)?;
+        let lhs = 2.0
+            * (0.4 * sigma / ((f.LoL - f.LoG) * G)).sqrt()
+            * (f.LoL / sigma).powf(0.6)
+            * (2.0 * fM / p.ID).powf(0.4)
+            * UM.powf(1.2);
+        let rhs = 0.725 + 4.15 * (UGS / UM).sqrt();
+
+        // bubble: only above the critical diameter, up to void fraction 0.25
+        let Dcrit = 19.0 * ((f.LoL - f.LoG) * sigma / (f.LoL.powi(2) * G)).sqrt();
+        let UGSB = ALFA_SLUG / (1.0 - ALFA_SLUG) * ULS + ALFA_SLUG * U0;
+
+        Ok(RegimeReport::new(
+            self.method(),
+            &[
+                ("UGSA", UGS / UGSA),
+                ("HLF", HLF / HLF_MAX),
+                ("dmax", lhs / rhs),
+                ("alfa", UGS / UM / ALFA_MAX),
+                ("Dcrit", Dcrit / p.ID),
+                ("UGSB", UGS / UGSB),
+            ],
+            Ansari::classify,
+        )
+        .geometric(&["Dcrit"]))
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let result = match regime {
+            Regime::VerticalUpAnnularFlow(..) => self.AnnularModel(input, regime),
+            Regime::VerticalUpSlugAndChurnFlow(..) => self.SlugModel(input, regime),
+            Regime::VerticalUpBubbleFlow(..) | Regime::VerticalUpFinelyDispersedBubbleFlow(..) => {
+                self.BubbleModel(input, regime)
+            }
+            _ => Err(TwoPhaseError::UnsupportedRegime(regime)),
+        }?;
+        result.checked()
+    }
+}
@@ -0,0 +1,212 @@
+#![allow(non_snake_case)]
+
+// Unified flow pattern prediction for any inclination, Barnea (1987),
+// "A unified model for predicting flow-pattern transitions for the whole range of
+// pipe inclinations", Int. J. Multiphase Flow 13, 1-12.
+//
+// Transitions, checked in this order:
+//   stratified / non-stratified : Taitel-Dukler momentum balance (Horizontal map)
+//   annular                     : film instability and slug blockage, Barnea (1986)
+//   dispersed bubble            : bubble break-up vs. coalescence, Barnea (1986)
+//   bubble / slug               : void fraction 0.25, steep large-diameter upflow only
+//   otherwise                   : intermittent
+// The pattern is reported with the Regime variant of the nearest orientation family
+// and the pressure drop taken from that family's model.
+
+use crate::dispatch::BAND_SWITCH;
+use crate::error::TwoPhaseError;
+use crate::horizontal::Horizontal;
+use crate::input::LineInput;
+use crate::result::LineResult;
+use crate::twoline::{FlowPattern, Regime, RegimeReport, TwoPhaseLine};
+use crate::vertical_down::VerticalDown;
+use crate::vertical_up::VerticalUp;
+
+const G: f64 = 9.81; // gravity accelerator [m/s^2]
+const GC: f64 = 9.8; // gravity constant [kg-m/kgf-s^2]
+
+const HLF_MAX: f64 = 0.12; // half of the slug liquid holdup 0.24, Barnea (1986)
+const ALFA_MAX: f64 = 0.52; // maximum packing void fraction of dispersed bubbles
+const ALFA_SLUG: f64 = 0.25; // void fraction at the bubble / slug transition
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Barnea;
+
+impl Barnea {
+    // Fanning friction factor by Blasius, f = C * Re^-n
+    fn blasius(Re: f64) -> f64 {
+        if Re < 2000.0 {
+            16.0 / Re
+        } else {
+            0.046 * Re.powf(-0.2)
+        }
+    }
+
+    // Regime variant of `pattern` for the orientation family nearest to `degree`
+    fn family(pattern: FlowPattern, degree: f64) -> Regime {
+        let up = degree <= -BAND_SWITCH;
+        let down = degree >= BAND_SWITCH;
+        match pattern {
+            FlowPattern::StratifiedSmooth => {
+                Regime::HorizontalStratifiedSmoothFlow(String::from("Stratified Smooth Flow"))
+            }
+            FlowPattern::StratifiedWavy => {
+                Regime::HorizontalStratifiedWavyFlow(String::from("Stratified Wavy Flow"))
+            }
+            FlowPattern::Annular if up => {
+                Regime::VerticalUpAnnularFlow(String::from("Vertical Up Annular Flow"))
+            }
+            FlowPattern::Annular if down => {
+                Regime::VerticalDownAnnularFlow(String::from("Annular Flow"))
+            }
+            FlowPattern::Annular => {
+                Regime::HorizontalAnnularDispersedFlow(String::from("Annular-Dispersed Flow"))
+            }
+            FlowPattern::Intermittent if up => {
+                Regime::VerticalUpSlugAndChurnFlow(String::from("Vertical Up Slug and Churn Flow"))
+            }
+            FlowPattern::Intermittent if down => {
+                Regime::VerticalDownSlugFlow(String::from("Slug Flow"))
+            }
+            FlowPattern::Intermittent => {
+                Regime::HorizontalIntermittentSlugFlow(String::from("Intermittent-Slug Flow"))
+            }
+            FlowPattern::Bubble => {
+                Regime::VerticalUpBubbleFlow(String::from("Vertical Up Bubble Flow"))
+            }
+            FlowPattern::DispersedBubble if up => Regime::VerticalUpFinelyDispersedBubbleFlow(
+                String::from("Vertical Up Finely Dispersed Bubble Flow"),
+            ),
+            FlowPattern::DispersedBubble if down => {
+                Regime::VerticalDownDispersedBubbleFlow(String::from("Dispersed-Bubble Flow"))
+            }
+            FlowPattern::DispersedBubble => {
+                Regime::HorizontalDispersedBubbleFlow(String::from("Dispersed Bubble Flow"))
+            }
+        }
+    }
+
+    // Taitel-Dukler ratios A (stratified / non-stratified) and C (smooth / wavy).
+    // Steep upflow has no equilibrium liquid level at all, so it is never stratified.
+    fn stratified(input: &LineInput) -> Result<(f64, f64), TwoPhaseError> {
+        match Horizontal.regime(input) {
+            Ok(report) => Ok((
+                report.ratio("A").unwrap_or(f64::INFINITY),
+                report.ratio("C").unwrap_or(f64::INFINITY),
+            )),
+            Err(TwoPhaseError::NotBracketed { .. }) => Ok((f64::INFINITY, f64::INFINITY)),
+            Err(e) => Err(e),
+        }
+    }
+
+    // transition ratios, each with its boundary at 1
+    fn ratios(&self, input: &LineInput) -> Result<Vec<(&'static str, f64)>, TwoPhaseError> {
+        let (ratio_a, ratio_c) = Barnea::stratified(input)?;
+
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let beta = -p.theta(); // inclination from horizontal, positive upward [rad]
+        let sigma = f.ST * GC; // surface tension [N/m]
+        let (UGS, ULS) = input.superficial_velocities(); // Superficial Velocity [m/s]
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+
+        // annular: Lockhart-Martinelli X^2 and gravity parameter Y with superficial gradients
+        let fSL = Barnea::blasius(f.LoL * ULS * p.ID / f.muL);
+        let fSG = Barnea::blasius(f.LoG * UGS * p.ID / f.muG);
+        let dPSL = 4.0 * fSL / p.ID * f.LoL * ULS.powi(2) / 2.0; // [Pa/m]
+        let dPSG = 4.0 * fSG / p.ID * f.LoG * UGS.powi(2) / 2.0; // [Pa/m]
+        let X2 = dPSL / dPSG;
+        let Y = (f.LoL - f.LoG) * G * beta.sin() / dPSG;
+        // film holdup from the annular momentum balance
+        let HLF = input
+            .solver
+            .brent(
+                "Barnea Annular: HLF",
+                |H: f64| (1.0 + 75.0 * H) / ((1.0 - H).powf(2.5) * H) - X2 / H.powi(3) - Y,
+                1e-9,
+                1.0 - 1e-9,
+            )?
+            .root;
+        let Ycrit = (2.0 - 1.5 * HLF) / (HLF.powi(3) * (1.0 - 1.5 * HLF)) * X2;
+
+        // dispersed bubble: maximum stable vs. critical bubble size
+        let fM = Barnea::blasius(f.LoL * UM * p.ID / f.muL);
+        let dmax = (0.725 + 4.15 * (UGS / UM).sqrt())
+            * (sigma / f.LoL).powf(0.6)
+            * (2.0 * fM * UM.powi(3) / p.ID).powf(-0.4);
+        let dCD = 2.0 * (0.4 * sigma / ((f.LoL - f.LoG) * G)).sqrt(); // deformation
+        let dCB = 3.0 / 8.0 * f.LoL / (f.LoL - f.LoG) * fM * UM.powi(2) / (G * beta.cos()); // creaming
+
+        // bubble: only for large diameters at steep upward inclination
+        let Dcrit = 19.0 * ((f.LoL - f.LoG) * sigma / (f.LoL.powi(2) * G)).sqrt();
+        let U0 = 1.53 * (G * (f.LoL - f.LoG) * sigma / f.LoL.powi(2)).powf(0.25); // rise velocity [m/s]
+        let steep = if beta > 0.0 {
+            beta.cos()
+                / beta.sin().powi(2)
+                / (0.75 * std::f64::consts::FRAC_PI_4.cos() * U0.powi(2) / (G * p.ID))
+        } else {
+            f64::INFINITY
+        };
+        let UGSB = ALFA_SLUG / (1.0 - ALFA_SLUG) * ULS + ALFA_SLUG * U0 * beta.sin(); // Curve bubble / slug
+
+        Ok(vec![
+            ("A", ratio_a),
+            ("C", ratio_c),
+            ("HLF", HLF / HLF_MAX),
+            ("Y", Y / Ycrit),
+            ("dmax", dmax / dCD.min(dCB)),
+            ("alfa", UGS / UM / ALFA_MAX),
+            ("Dcrit", Dcrit / p.ID),
+            ("beta", steep),
+            ("UGSB", UGS / UGSB),
+        ])
+    }
+
+    // flow pattern from the ratios, in the order returned by `ratios`
+    fn classify(r: &[f64]) -> FlowPattern {
+        let (ratio_a, ratio_c, hlf, y, dmax, alfa, dcrit, beta, ugsb) =
+            (r[0], r[1], r[2], r[3], r[4], r[5], r[6], r[7], r[8]);
+        if ratio_a <= 1.0 {
+            if ratio_c <= 1.0 {
+                FlowPattern::StratifiedSmooth
+            } else {
+                FlowPattern::StratifiedWavy
+            }
+        } else if hlf < 1.0 && y < 1.0 {
+            FlowPattern::Annular
+        } else if dmax < 1.0 && alfa <= 1.0 {
+            FlowPattern::DispersedBubble
+        } else if dcrit < 1.0 && beta < 1.0 && ugsb < 1.0 {
+            FlowPattern::Bubble
+        } else {
+            FlowPattern::Intermittent
+        }
+    }
+}
+
+impl TwoPhaseLine for Barnea {
+    fn method(&self) -> &'static str {
+        "Barnea (1987) unified inclined"
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        let degree = input.pipe.degree;
+        Ok(RegimeReport::new(self.method(), &self.ratios(input)?, |r| {
+            Barnea::family(Barnea::classify(r), degree)
+        })
+        .geometric(&["Dcrit", "beta"]))
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        match regime {
+            Regime::VerticalUpAnnularFlow(..)
+            | Regime::VerticalUpBubbleFlow(..)
+            | Regime::VerticalUpSlugAndChurnFlow(..)
+            | Regime::VerticalUpFinelyDispersedBubbleFlow(..) => VerticalUp.model(input, regime),
+            Regime::VerticalDownAnnularFlow(..)
+            | Regime::VerticalDownSlugFlow(..)
+            | Regime::VerticalDownDispersedBubbleFlow(..) => VerticalDown.model(input, regime),
+            _ => Horizontal.model(input, regime),
+        }
+    }
+}
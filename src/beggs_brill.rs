@@ -0,0 +1,182 @@
+#![allow(non_snake_case)]
+
+// Beggs and Brill (1973), "A study of two-phase flow in inclined pipes",
+// J. Petroleum Technology 25, 607-617, with the Payne et al. (1979) corrections:
+// holdup multiplied by 0.924 horizontal and uphill and 0.685 downhill, and the no-slip
+// friction factor taken for a rough pipe.
+//
+// The regime (segregated / transition / intermittent / distributed) is read from the
+// horizontal map on the no-slip holdup and mixture Froude number. The horizontal
+// holdup is corrected for the inclination `degree` and the two-phase friction factor
+// is the no-slip one times the ratio exp(S).
+
+use crate::error::TwoPhaseError;
+use crate::input::LineInput;
+use crate::result::{LineResult, RegimeDetail};
+use crate::twoline::{Regime, RegimeReport, TwoPhaseLine};
+
+const G: f64 = 9.81; // gravity accelerator [m/s^2]
+const GC: f64 = 9.8; // gravity constant [kg-m/kgf-s^2]
+
+const PAYNE_UP: f64 = 0.924; // holdup correction, horizontal and uphill
+const PAYNE_DOWN: f64 = 0.685; // holdup correction, downhill
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BeggsBrill;
+
+// Horizontal map boundaries L1-L4 on the mixture Froude number, for no-slip holdup `Landa`
+fn boundaries(Landa: f64) -> (f64, f64, f64, f64) {
+    (
+        316.0 * Landa.powf(0.302),
+        0.0009252 * Landa.powf(-2.4684),
+        0.10 * Landa.powf(-1.4516),
+        0.5 * Landa.powf(-6.738),
+    )
+}
+
+impl BeggsBrill {
+    // no-slip holdup [-] and mixture Froude number [-]
+    fn landa_froude(&self, input: &LineInput) -> (f64, f64) {
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS;
+        (ULS / UM, UM.powi(2) / (G * input.pipe.ID))
+    }
+
+    // inclined holdup of the segregated, intermittent or distributed pattern
+    fn pattern_holdup(&self, input: &LineInput, pattern: &Regime) -> f64 {
+        let f = &input.fluid;
+        let beta = -input.pipe.theta(); // inclination from horizontal, positive upward [rad]
+        let (_, ULS) = input.superficial_velocities();
+        let (Landa, NFr) = self.landa_froude(input);
+
+        let (a, b, c) = match pattern {
+            Regime::BeggsBrillSegregatedFlow(..) => (0.98, 0.4846, 0.0868),
+            Regime::BeggsBrillIntermittentFlow(..) => (0.845, 0.5351, 0.0173),
+            _ => (1.065, 0.5824, 0.0609),
+        };
+        let HL0 = (a * Landa.powf(b) / NFr.powf(c)).max(Landa); // horizontal holdup [-]
+
+        let NLV = ULS * (f.LoL / (G * f.ST * GC)).powf(0.25); // liquid velocity number [-]
+        let coefficients = if beta < 0.0 {
+            Some((4.70, -0.3692, 0.1244, -0.5056))
+        } else {
+            match pattern {
+                Regime::BeggsBrillSegregatedFlow(..) => Some((0.011, -3.768, 3.539, -1.614)),
+                Regime::BeggsBrillIntermittentFlow(..) => Some((2.96, 0.305, -0.4473, 0.0978)),
+                _ => None, // no correction for distributed uphill flow
+            }
+        };
+        let C = match coefficients {
+            Some((e, ff, g, h)) => {
+                ((1.0 - Landa) * (e * Landa.powf(ff) * NLV.powf(g) * NFr.powf(h)).ln()).max(0.0)
+            }
+            None => 0.0,
+        };
+        let Psi = 1.0 + C * ((1.8 * beta).sin() - 0.333 * (1.8 * beta).sin().powi(3));
+        let payne = if beta >= 0.0 { PAYNE_UP } else { PAYNE_DOWN };
+        // the corrected holdup is kept above the no-slip value
+        (HL0 * Psi * payne).max(Landa).min(1.0)
+    }
+
+    fn holdup(&self, input: &LineInput, regime: &Regime) -> f64 {
+        match regime {
+            Regime::BeggsBrillTransitionFlow(..) => {
+                let (Landa, NFr) = self.landa_froude(input);
+                let (_, L2, L3, _) = boundaries(Landa);
+                let A = (L3 - NFr) / (L3 - L2);
+                let segregated = Regime::BeggsBrillSegregatedFlow(String::new());
+                let intermittent = Regime::BeggsBrillIntermittentFlow(String::new());
+                A * self.pattern_holdup(input, &segregated)
+                    + (1.0 - A) * self.pattern_holdup(input, &intermittent)
+            }
+            _ => self.pattern_holdup(input, regime),
+        }
+    }
+
+    fn Model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let (Landa, _) = self.landa_froude(input);
+        let HL = self.holdup(input, &regime);
+
+        // friction factor ratio on the no-slip friction factor
+        let LoNS = f.LoL * Landa + f.LoG * (1.0 - Landa); // no-slip density [kg/m^3]
+        let muNS = f.muL * Landa + f.muG * (1.0 - Landa); // no-slip viscosity [kg/m-s]
+        let fNS = input.fanning(LoNS * UM * p.ID / muNS)?;
+        let y = Landa / HL.powi(2);
+        let S = if y > 1.0 && y < 1.2 {
+            (2.2 * y - 1.2).ln()
+        } else {
+            let x = y.ln();
+            x / (-0.0523 + 3.182 * x - 0.8725 * x.powi(2) + 0.01853 * x.powi(4))
+        };
+        let fTP = fNS * S.exp();
+
+        let density = f.LoL * HL + f.LoG * (1.0 - HL); // slip density [kg/m^3]
+        let Pfric = 4.0 * fTP * LoNS * UM.powi(2) / (2.0 * G * p.ID) / 10000.0 * 100.0 * input.SF;
+        Ok(LineResult {
+            regime,
+            holdup: HL,
+            density,
+            velocity: UM,
+            Pfric,
+            Pgrav: density * p.rise_per_length() / 10000.0 * 100.0,
+            Pacc: 0.0,
+            Head: LoNS * UM.powi(2) / (2.0 * G) / 10000.0,
+            Ef: (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0, // must transfer to imperial unit
+            detail: Some(RegimeDetail::phase_velocities(ULS, UGS, HL)),
+            blend: None,
+            fallback: None,
+        })
+    }
+
+    // regime from the transition ratios, in the order given to RegimeReport::new
+    fn classify(r: &[f64]) -> Regime {
+        let (landa1, landa4, l1, l2, l3, l4) = (r[0], r[1], r[2], r[3], r[4], r[5]);
+        if (landa1 < 1.0 && l1 < 1.0) || (landa1 >= 1.0 && l2 < 1.0) {
+            Regime::BeggsBrillSegregatedFlow(String::from("Segregated Flow"))
+        } else if landa1 >= 1.0 && l3 <= 1.0 {
+            Regime::BeggsBrillTransitionFlow(String::from("Transition Flow"))
+        } else if (landa4 < 1.0 && l1 < 1.0) || (landa4 >= 1.0 && l4 <= 1.0) {
+            Regime::BeggsBrillIntermittentFlow(String::from("Intermittent Flow"))
+        } else {
+            Regime::BeggsBrillDistributedFlow(String::from("Distributed Flow"))
+        }
+    }
+}
+
+impl TwoPhaseLine for BeggsBrill {
+    fn method(&self) -> &'static str {
+        "Beggs-Brill (1973) with Payne correction"
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        let (Landa, NFr) = self.landa_froude(input);
+        let (L1, L2, L3, L4) = boundaries(Landa);
+        Ok(RegimeReport::new(
+            self.method(),
+            &[
+                ("Landa1", Landa / 0.01),
+                ("Landa4", Landa / 0.4),
+                ("L1", NFr / L1),
+                ("L2", NFr / L2),
+                ("L3", NFr / L3),
+                ("L4", NFr / L4),
+            ],
+            BeggsBrill::classify,
+        ))
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let result = match regime {
+            Regime::BeggsBrillSegregatedFlow(..)
+            | Regime::BeggsBrillTransitionFlow(..)
+            | Regime::BeggsBrillIntermittentFlow(..)
+            | Regime::BeggsBrillDistributedFlow(..) => self.Model(input, regime),
+            _ => Err(TwoPhaseError::UnsupportedRegime(regime)),
+        }?;
+        result.checked()
+    }
+}
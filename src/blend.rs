@@ -0,0 +1,87 @@
+// Optional blending of the pressure-drop models across a regime transition.
+//
+// Within `band` of the nearest transition (margin = |ratio - 1| < band, see
+// RegimeReport) the result is interpolated between the model of the predicted regime
+// and the model of the neighbouring regime. The neighbour's weight falls linearly from
+// 0.5 on the boundary to 0 at the edge of the band, so the gradient is continuous
+// across the transition. Outside the band the result is the unblended model.
+
+use crate::error::TwoPhaseError;
+use crate::input::LineInput;
+use crate::result::{Blend, LineResult};
+use crate::twoline::{Regime, RegimeReport, TwoPhaseLine};
+
+pub const DEFAULT_BAND: f64 = 0.1; // [-]
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Blended<L> {
+    pub line: L,
+    pub band: f64, // half width of the blending band in transition ratio [-]
+}
+
+impl<L: TwoPhaseLine> Blended<L> {
+    pub fn new(line: L) -> Self {
+        Blended {
+            line,
+            band: DEFAULT_BAND,
+        }
+    }
+
+    pub fn band(mut self, band: f64) -> Self {
+        self.band = band;
+        self
+    }
+}
+
+fn mix(a: f64, b: f64, weight: f64) -> f64 {
+    (1.0 - weight) * a + weight * b
+}
+
+impl<L: TwoPhaseLine> TwoPhaseLine for Blended<L> {
+    fn method(&self) -> &'static str {
+        self.line.method()
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        self.line.regime(input)
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        self.line.model(input, regime)
+    }
+
+    // The base result is the wrapped line's own gradient, so a wrapper such as Fallback
+    // keeps its behaviour. The neighbour's model is only a correction: if it fails, or
+    // the base result already fell back, the unblended result is returned.
+    fn gradient(&self, input: &LineInput) -> Result<LineResult, TwoPhaseError> {
+        let result = self.line.gradient(input)?;
+        if result.fallback.is_some() {
+            return Ok(result);
+        }
+        let report = match self.line.regime(input) {
+            Ok(report) => report,
+            Err(_) => return Ok(result),
+        };
+        let neighbour = match report.neighbour {
+            Some(neighbour) if report.margin < self.band => neighbour,
+            _ => return Ok(result),
+        };
+        let other = match self.line.model(input, neighbour.clone()) {
+            Ok(other) => other,
+            Err(_) => return Ok(result),
+        };
+        let weight = 0.5 * (1.0 - report.margin / self.band);
+        Ok(LineResult {
+            holdup: mix(result.holdup, other.holdup, weight),
+            density: mix(result.density, other.density, weight),
+            velocity: mix(result.velocity, other.velocity, weight),
+            Pfric: mix(result.Pfric, other.Pfric, weight),
+            Pgrav: mix(result.Pgrav, other.Pgrav, weight),
+            Pacc: mix(result.Pacc, other.Pacc, weight),
+            Head: mix(result.Head, other.Head, weight),
+            Ef: mix(result.Ef, other.Ef, weight),
+            blend: Some(Blend { neighbour, weight }),
+            ..result
+        })
+    }
+}
@@ -0,0 +1,119 @@
+// Orientation dispatch from the pipe inclination [degree], Horizontal = 0, -Up / +Down.
+//
+//   |degree| <= 10              : Horizontal (Taitel-Dukler, inclination terms included)
+//   degree <= -80 / degree >= 80 : VerticalUp / VerticalDown
+//   10 < |degree| < 80          : Barnea (1987) unified map, which reports the regime of
+//                                 the nearer orientation family (switching at 45 degree)
+//
+// Neither the horizontal nor the vertical maps are valid inside the 10-80 band. The
+// selection can be overridden per line. The models take the inclination from the input,
+// so an input inclined otherwise than the selection is rejected unless overridden.
+
+use crate::barnea::Barnea;
+use crate::error::TwoPhaseError;
+use crate::horizontal::Horizontal;
+use crate::input::{InputError, LineInput};
+use crate::result::LineResult;
+use crate::twoline::{Regime, RegimeReport, TwoPhaseLine};
+use crate::vertical_down::VerticalDown;
+use crate::vertical_up::VerticalUp;
+
+pub const HORIZONTAL_LIMIT: f64 = 10.0; // [degree]
+pub const VERTICAL_LIMIT: f64 = 80.0; // [degree]
+pub const BAND_SWITCH: f64 = 45.0; // [degree]
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Horizontal,
+    VerticalUp,
+    VerticalDown,
+    Inclined,
+}
+
+impl Orientation {
+    pub fn for_inclination(degree: f64) -> Self {
+        if degree.abs() <= HORIZONTAL_LIMIT {
+            Orientation::Horizontal
+        } else if degree <= -VERTICAL_LIMIT {
+            Orientation::VerticalUp
+        } else if degree >= VERTICAL_LIMIT {
+            Orientation::VerticalDown
+        } else {
+            Orientation::Inclined
+        }
+    }
+
+    fn line(self) -> &'static dyn TwoPhaseLine {
+        match self {
+            Orientation::Horizontal => &Horizontal,
+            Orientation::VerticalUp => &VerticalUp,
+            Orientation::VerticalDown => &VerticalDown,
+            Orientation::Inclined => &Barnea,
+        }
+    }
+}
+
+// The regime map and models selected for one inclination.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dispatch {
+    pub degree: f64,              // inclination the selection was made for [degree]
+    pub orientation: Orientation, // selected orientation
+    pub overridden: bool,         // true if the orientation was set by the caller
+}
+
+impl Dispatch {
+    pub fn new(degree: f64) -> Self {
+        Dispatch {
+            degree,
+            orientation: Orientation::for_inclination(degree),
+            overridden: false,
+        }
+    }
+
+    // use the given orientation whatever the inclination
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self.overridden = true;
+        self
+    }
+
+    // true if the inclination lies between the horizontal and vertical limits
+    pub fn in_band(&self) -> bool {
+        let a = self.degree.abs();
+        a > HORIZONTAL_LIMIT && a < VERTICAL_LIMIT
+    }
+
+    fn check(&self, input: &LineInput) -> Result<(), InputError> {
+        if !self.overridden && input.pipe.degree != self.degree {
+            return Err(InputError::InclinationMismatch {
+                selected: self.degree,
+                degree: input.pipe.degree,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl TwoPhaseLine for Dispatch {
+    fn method(&self) -> &'static str {
+        self.orientation.line().method()
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        self.check(input)?;
+        self.orientation.line().regime(input)
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        self.check(input)?;
+        self.orientation.line().model(input, regime)
+    }
+}
+
+impl dyn TwoPhaseLine {
+    // `<dyn TwoPhaseLine>::for_inclination(-90.0)` picks the map for a riser, etc.; the
+    // input must be inclined the same
+    pub fn for_inclination(degree: f64) -> Dispatch {
+        Dispatch::new(degree)
+    }
+}
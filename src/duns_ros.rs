@@ -0,0 +1,301 @@
+#![allow(non_snake_case)]
+
+// Duns and Ros (1963), "Vertical flow of gas and liquid mixtures in wells",
+// Proc. 6th World Petroleum Congress, as given by Brill and Mukherjee (1999).
+//
+// Regions on the gas velocity number NGV:
+//   Region I   (bubble, plug, part of froth) : NGV < L1 + L2 NLV
+//   Region II  (slug, rest of froth)         : NGV < 50 + 36 NLV
+//   Transition                               : NGV < 75 + 84 NLV^0.75
+//   Region III (mist)                        : otherwise
+// The slip factors F1-F7 and L1, L2 are read from the charts of the paper, digitized
+// at the points of the tables below and interpolated in log NL (log ND). The map and
+// models take the pipe as vertical; only the elevation term follows the inclination.
+
+use crate::error::TwoPhaseError;
+use crate::input::LineInput;
+use crate::result::{LineResult, RegimeDetail};
+use crate::twoline::{Regime, RegimeReport, TwoPhaseLine};
+
+const G: f64 = 9.81; // gravity accelerator [m/s^2]
+const GC: f64 = 9.8; // gravity constant [kg-m/kgf-s^2]
+
+// slip factors against the liquid viscosity number NL
+const NL: [f64; 11] = [
+    0.001, 0.002, 0.005, 0.01, 0.02, 0.05, 0.1, 0.2, 0.5, 1.0, 2.0,
+];
+const F1: [f64; 11] = [
+    1.30, 1.30, 1.30, 1.32, 1.40, 1.60, 1.80, 1.95, 1.90, 1.50, 1.00,
+];
+const F2: [f64; 11] = [
+    0.24, 0.24, 0.24, 0.23, 0.20, 0.15, 0.12, 0.15, 0.35, 0.70, 1.10,
+];
+const F3: [f64; 11] = [
+    0.80, 0.85, 0.95, 1.05, 1.20, 1.50, 1.85, 2.30, 3.00, 3.50, 3.80,
+];
+const F4: [f64; 11] = [
+    0.0, 2.0, 8.0, 16.0, 26.0, 40.0, 50.0, 56.0, 58.0, 58.0, 58.0,
+];
+const F5: [f64; 11] = [
+    0.22, 0.21, 0.20, 0.18, 0.15, 0.11, 0.08, 0.065, 0.07, 0.10, 0.14,
+];
+const F6: [f64; 11] = [
+    0.80, 0.85, 0.95, 1.10, 1.30, 1.65, 1.90, 2.00, 1.60, 1.00, 0.60,
+];
+const F7: [f64; 11] = [
+    0.14, 0.13, 0.11, 0.09, 0.075, 0.055, 0.045, 0.035, 0.027, 0.022, 0.020,
+];
+
+// Region I / II boundary factors against the pipe diameter number ND
+const ND: [f64; 9] = [10.0, 15.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 100.0];
+const L1: [f64; 9] = [2.00, 1.75, 1.50, 1.20, 1.10, 1.03, 1.00, 1.00, 1.00];
+const L2: [f64; 9] = [0.50, 0.65, 0.80, 0.95, 1.03, 1.08, 1.10, 1.10, 1.10];
+
+// friction factor correction f2 against f1 R ND^(2/3)
+const F2X: [f64; 6] = [0.001, 0.01, 0.1, 1.0, 10.0, 100.0];
+const F2Y: [f64; 6] = [1.00, 0.97, 0.85, 0.60, 0.35, 0.20];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DunsRos;
+
+// chart value at x, linear in log10(x) and held constant beyond the table
+fn chart(x: f64, xs: &[f64], ys: &[f64]) -> f64 {
+    let n = xs.len();
+    if x <= xs[0] {
+        return ys[0];
+    }
+    if x >= xs[n - 1] {
+        return ys[n - 1];
+    }
+    let k = xs.iter().position(|&v| v > x).unwrap_or(n - 1);
+    let t = (x.log10() - xs[k - 1].log10()) / (xs[k].log10() - xs[k - 1].log10());
+    ys[k - 1] + t * (ys[k] - ys[k - 1])
+}
+
+// dimensionless groups of Duns and Ros
+struct Numbers {
+    k: f64,   // (LoL / (g sigma))^0.25 [s/m]
+    NLV: f64, // liquid velocity number
+    NGV: f64, // gas velocity number
+    ND: f64,  // pipe diameter number
+    NL: f64,  // liquid viscosity number
+}
+
+impl DunsRos {
+    fn numbers(&self, input: &LineInput) -> Numbers {
+        let f = &input.fluid;
+        let sigma = f.ST * GC; // surface tension [N/m]
+        let (UGS, ULS) = input.superficial_velocities();
+        let k = (f.LoL / (G * sigma)).powf(0.25);
+        Numbers {
+            k,
+            NLV: ULS * k,
+            NGV: UGS * k,
+            ND: input.pipe.ID * (f.LoL * G / sigma).sqrt(),
+            NL: f.muL * (G / (f.LoL * sigma.powi(3))).powf(0.25),
+        }
+    }
+
+    // Region I and II: holdup from the slip velocity, friction on the liquid phase
+    fn SlipModel(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let n = self.numbers(input);
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let Landa = ULS / UM; // no-slip holdup [-]
+
+        let S = match regime {
+            Regime::DunsRosBubbleFlow(..) => {
+                let F3p = chart(n.NL, &NL, &F3) - chart(n.NL, &NL, &F4) / n.ND;
+                chart(n.NL, &NL, &F1)
+                    + chart(n.NL, &NL, &F2) * n.NLV
+                    + F3p * (n.NGV / (1.0 + n.NLV)).powi(2)
+            }
+            _ => {
+                let F6p = 0.029 * n.ND + chart(n.NL, &NL, &F6);
+                (1.0 + chart(n.NL, &NL, &F5)) * (n.NGV.powf(0.982) + F6p)
+                    / (1.0 + chart(n.NL, &NL, &F7) * n.NLV).powi(2)
+            }
+        }; // slip velocity number [-]
+        let Vs = S / n.k; // slip velocity [m/s]
+        let HL = if Vs > 0.0 {
+            (Vs - UM + ((UM - Vs).powi(2) + 4.0 * Vs * ULS).sqrt()) / (2.0 * Vs)
+        } else {
+            Landa
+        };
+
+        // friction factor f1 f2 / f3 on the liquid, Darcy
+        let f1 = input.fanning(f.LoL * ULS * p.ID / f.muL)? * 4.0;
+        let R = UGS / ULS;
+        let f2 = chart(f1 * R * n.ND.powf(2.0 / 3.0), &F2X, &F2Y);
+        let f3 = 1.0 + f1 * (R / 50.0).sqrt();
+        let fTP = f1 * f2 / f3;
+        let Pfric = fTP * f.LoL * ULS * UM / (2.0 * G * p.ID) / 10000.0 * 100.0 * input.SF;
+
+        let density = f.LoL * HL + f.LoG * (1.0 - HL);
+        let LoNS = f.LoL * Landa + f.LoG * (1.0 - Landa); // no-slip density [kg/m^3]
+        Ok(LineResult {
+            regime,
+            holdup: HL,
+            density,
+            velocity: UM,
+            Pfric,
+            Pgrav: density * p.rise_per_length() / 10000.0 * 100.0,
+            Pacc: 0.0,
+            Head: LoNS * UM.powi(2) / (2.0 * G) / 10000.0,
+            Ef: (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0, // must transfer to imperial unit
+            detail: Some(RegimeDetail::phase_velocities(ULS, UGS, HL)),
+            blend: None,
+            fallback: None,
+        })
+    }
+
+    // Region III: no slip, friction on the gas with the roughness of the liquid film
+    fn MistModel(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let sigma = f.ST * GC; // surface tension [N/m]
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let Landa = ULS / UM; // no-slip holdup [-]
+
+        // Weber number NW = LoG UGS^2 rough / sigma times the film viscosity number
+        // Nmu = muL^2 / (LoL sigma rough); the roughness cancels, so a smooth pipe is fine
+        let NWNmu = f.LoG * UGS.powi(2) * f.muL.powi(2) / (f.LoL * sigma.powi(2));
+        let rough = if NWNmu <= 0.005 {
+            0.0749 * sigma / (f.LoG * UGS.powi(2))
+        } else {
+            0.3713 * sigma * NWNmu.powf(0.302) / (f.LoG * UGS.powi(2))
+        };
+        let e = (rough / p.ID).clamp(1e-3, 0.5); // relative film roughness [-]
+        let fTP = if e > 0.05 {
+            4.0 * (1.0 / (4.0 * (0.27 * e).log10()).powi(2) + 0.067 * e.powf(1.73))
+        } else {
+            input
+                .friction
+                .fanning(f.LoG * UGS * p.ID / f.muG, e, input.solver)?
+                * 4.0
+        };
+        let Pfric = fTP * f.LoG * UGS.powi(2) / (2.0 * G * p.ID) / 10000.0 * 100.0 * input.SF;
+
+        let LoNS = f.LoL * Landa + f.LoG * (1.0 - Landa); // no-slip density [kg/m^3]
+        Ok(LineResult {
+            regime,
+            holdup: Landa,
+            density: LoNS,
+            velocity: UM,
+            Pfric,
+            Pgrav: LoNS * p.rise_per_length() / 10000.0 * 100.0,
+            Pacc: 0.0,
+            Head: LoNS * UM.powi(2) / (2.0 * G) / 10000.0,
+            Ef: (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0, // must transfer to imperial unit
+            detail: Some(RegimeDetail::phase_velocities(ULS, UGS, Landa)),
+            blend: None,
+            fallback: None,
+        })
+    }
+
+    // linear in NGV between Region II at its upper limit and mist at its lower limit
+    fn TransitionModel(
+        &self,
+        input: &LineInput,
+        regime: Regime,
+    ) -> Result<LineResult, TwoPhaseError> {
+        let n = self.numbers(input);
+        let Lm = 50.0 + 36.0 * n.NLV;
+        let Ls = 75.0 + 84.0 * n.NLV.powf(0.75);
+        let A = (Ls - n.NGV) / (Ls - Lm);
+        let slug = self.SlipModel(input, Regime::DunsRosSlugFlow(String::new()))?;
+        let mist = self.MistModel(input, Regime::DunsRosMistFlow(String::new()))?;
+        let mix = |a: f64, b: f64| A * a + (1.0 - A) * b;
+        let (UGS, ULS) = input.superficial_velocities();
+        let holdup = mix(slug.holdup, mist.holdup);
+        Ok(LineResult {
+            regime,
+            holdup,
+            density: mix(slug.density, mist.density),
+            Pfric: mix(slug.Pfric, mist.Pfric),
+            Pgrav: mix(slug.Pgrav, mist.Pgrav),
+            detail: Some(RegimeDetail::phase_velocities(ULS, UGS, holdup)),
+            ..slug
+        })
+    }
+
+    // regime from the transition ratios, in the order given to RegimeReport::new
+    fn classify(r: &[f64]) -> Regime {
+        let (region_i, region_ii, mist) = (r[0], r[1], r[2]);
+        if region_i < 1.0 {
+            Regime::DunsRosBubbleFlow(String::from("Bubble Flow (Region I)"))
+        } else if region_ii < 1.0 {
+            Regime::DunsRosSlugFlow(String::from("Slug Flow (Region II)"))
+        } else if mist < 1.0 {
+            Regime::DunsRosTransitionFlow(String::from("Transition Flow"))
+        } else {
+            Regime::DunsRosMistFlow(String::from("Mist Flow (Region III)"))
+        }
+    }
+}
+
+impl TwoPhaseLine for DunsRos {
+    fn method(&self) -> &'static str {
+        "Duns-Ros (1963) vertical upflow"
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        let n = self.numbers(input);
+        let L1L2 = chart(n.ND, &ND, &L1) + chart(n.ND, &ND, &L2) * n.NLV;
+        Ok(RegimeReport::new(
+            self.method(),
+            &[
+                ("I", n.NGV / L1L2),
+                ("II", n.NGV / (50.0 + 36.0 * n.NLV)),
+                ("III", n.NGV / (75.0 + 84.0 * n.NLV.powf(0.75))),
+            ],
+            DunsRos::classify,
+        ))
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let result = match regime {
+            Regime::DunsRosBubbleFlow(..) | Regime::DunsRosSlugFlow(..) => {
+                self.SlipModel(input, regime)
+            }
+            Regime::DunsRosTransitionFlow(..) => self.TransitionModel(input, regime),
+            Regime::DunsRosMistFlow(..) => self.MistModel(input, regime),
+            _ => Err(TwoPhaseError::UnsupportedRegime(regime)),
+        }?;
+        result.checked()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(rough: f64) -> LineInput {
+        LineInput::builder()
+            .liquid_mass_flow(5000.0) // [kg/hr]
+            .vapor_mass_flow(40000.0) // [kg/hr]
+            .liquid_density(790.99) // [kg/m^3]
+            .vapor_density(4.581) // [kg/m^3]
+            .liquid_viscosity(0.241) // [cP]
+            .vapor_viscosity(0.0091) // [cP]
+            .surface_tension(14.78) // [dyne/cm]
+            .inside_diameter(102.26) // [mm]
+            .roughness(rough) // [mm]
+            .inclination(-90.0) // [degree]
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn mist_model_on_a_smooth_pipe() {
+        let mist = || Regime::DunsRosMistFlow(String::new());
+        let smooth = DunsRos.model(&input(0.0), mist()).unwrap();
+        assert!(smooth.Pfric.is_finite() && smooth.Pfric > 0.0);
+        // the film roughness, not the pipe roughness, sets the friction
+        let rough = DunsRos.model(&input(0.04572), mist()).unwrap();
+        assert_eq!(smooth.Pfric, rough.Pfric);
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+
+use crate::input::InputError;
+use crate::twoline::Regime;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TwoPhaseError {
+    // an iterative solution did not reach the allowable tolerance
+    NonConvergence {
+        solver: &'static str,
+        iterations: usize,
+        residual: f64,
+    },
+    // a bracketing solver was given an interval without a sign change
+    NotBracketed {
+        solver: &'static str,
+        lower: f64,
+        upper: f64,
+    },
+    InvalidInput(InputError),
+    // a correlation produced or needed a value outside its valid range
+    OutOfRange {
+        parameter: &'static str,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+    // no model is available for the identified flow regime
+    UnsupportedRegime(Regime),
+}
+
+impl TwoPhaseError {
+    // Err(OutOfRange) unless min <= value <= max (NaN is always out of range)
+    pub(crate) fn check_range(
+        parameter: &'static str,
+        value: f64,
+        min: f64,
+        max: f64,
+    ) -> Result<f64, TwoPhaseError> {
+        if (min..=max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(TwoPhaseError::OutOfRange {
+                parameter,
+                value,
+                min,
+                max,
+            })
+        }
+    }
+}
+
+impl fmt::Display for TwoPhaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TwoPhaseError::NonConvergence {
+                solver,
+                iterations,
+                residual,
+            } => write!(
+                f,
+                "{} did not converge after {} iterations (residual {:e})",
+                solver, iterations, residual
+            ),
+            TwoPhaseError::NotBracketed {
+                solver,
+                lower,
+                upper,
+            } => write!(f, "{}: no sign change over [{}, {}]", solver, lower, upper),
+            TwoPhaseError::InvalidInput(e) => write!(f, "invalid input: {}", e),
+            TwoPhaseError::OutOfRange {
+                parameter,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "{} = {} is outside the correlation range [{}, {}]",
+                parameter, value, min, max
+            ),
+            TwoPhaseError::UnsupportedRegime(regime) => {
+                write!(f, "no model for flow regime << {:?} >>", regime)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TwoPhaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TwoPhaseError::InvalidInput(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<InputError> for TwoPhaseError {
+    fn from(e: InputError) -> Self {
+        TwoPhaseError::InvalidInput(e)
+    }
+}
@@ -0,0 +1,268 @@
+#![allow(non_snake_case)]
+
+// Two-phase pressure loss through fittings and valves [kgf/cm^2], to be added to the
+// straight-run loss of the line.
+//
+// The single phase resistance is K = fT (L/D), with the L/D of Crane TP-410 and fT
+// the fully turbulent Darcy friction factor of the pipe. It is applied to the total
+// flow as liquid and multiplied by a two-phase multiplier:
+//   - bends and tees : Chisholm (1983) B coefficient,
+//                      phi2 = 1 + (LoL/LoG - 1) (B x (1 - x) + x^2)
+//   - valves         : homogeneous, phi2 = 1 + x (LoL/LoG - 1), or separated (Chisholm B)
+//   - sudden expansion / contraction : momentum balance on the area change with the
+//                      homogeneous multiplier or the separated momentum multiplier
+//                      (1 - x)^2 / (1 - a) + LoL/LoG x^2 / a, void fraction a by Zivi (1964)
+// The loss is the static pressure change p1 - p2 across the fitting, so a sudden
+// expansion, which recovers pressure, gives a negative loss. The safety factor scales
+// losses only; a recovery is never enlarged by it.
+
+use crate::error::TwoPhaseError;
+use crate::input::{in_range, positive, LineInput, PipeGeometry};
+
+const G: f64 = 9.81; // gravity accelerator [m/s^2]
+const RE_ROUGH: f64 = 1.0e8; // Reynolds number of fully turbulent flow [-]
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fitting {
+    Elbow90,           // 90 degree elbow, R/D 1
+    Elbow90LongRadius, // 90 degree elbow, R/D 1.5
+    Elbow45,           // 45 degree elbow, R/D 1
+    ReturnBend,        // 180 degree close return bend
+    TeeRun,            // tee, flow through run
+    TeeBranch,         // tee, flow through branch
+    GateValve,
+    GlobeValve,
+    BallValve,
+    PlugValve,
+    ButterflyValve,
+    SwingCheckValve,
+    LiftCheckValve,
+    Expansion { pipe: PipeGeometry }, // sudden expansion to the downstream pipe
+    Contraction { pipe: PipeGeometry }, // sudden contraction to the downstream pipe
+}
+
+// Two-phase multiplier of a valve or an area change
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FittingModel {
+    Homogeneous,
+    #[default]
+    Separated,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FittingResult {
+    pub fitting: Fitting,
+    pub K: f64,    // single phase resistance on the upstream velocity [-]
+    pub phi2: f64, // two-phase multiplier on the all-liquid loss [-]
+    pub dP: f64,   // static pressure loss p1 - p2 [kgf/cm^2]
+}
+
+impl Fitting {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Fitting::Elbow90 => "90 degree elbow",
+            Fitting::Elbow90LongRadius => "90 degree long radius elbow",
+            Fitting::Elbow45 => "45 degree elbow",
+            Fitting::ReturnBend => "180 degree return bend",
+            Fitting::TeeRun => "tee, run",
+            Fitting::TeeBranch => "tee, branch",
+            Fitting::GateValve => "gate valve",
+            Fitting::GlobeValve => "globe valve",
+            Fitting::BallValve => "ball valve",
+            Fitting::PlugValve => "plug valve",
+            Fitting::ButterflyValve => "butterfly valve",
+            Fitting::SwingCheckValve => "swing check valve",
+            Fitting::LiftCheckValve => "lift check valve",
+            Fitting::Expansion { .. } => "sudden expansion",
+            Fitting::Contraction { .. } => "sudden contraction",
+        }
+    }
+
+    // equivalent length in pipe diameters, Crane TP-410; None for an area change
+    pub fn LD(&self) -> Option<f64> {
+        match self {
+            Fitting::Elbow90 => Some(20.0),
+            Fitting::Elbow90LongRadius => Some(14.0),
+            Fitting::Elbow45 => Some(16.0),
+            Fitting::ReturnBend => Some(50.0),
+            Fitting::TeeRun => Some(20.0),
+            Fitting::TeeBranch => Some(60.0),
+            Fitting::GateValve => Some(8.0),
+            Fitting::GlobeValve => Some(340.0),
+            Fitting::BallValve => Some(3.0),
+            Fitting::PlugValve => Some(18.0),
+            Fitting::ButterflyValve => Some(45.0),
+            Fitting::SwingCheckValve => Some(100.0),
+            Fitting::LiftCheckValve => Some(600.0),
+            Fitting::Expansion { .. } | Fitting::Contraction { .. } => None,
+        }
+    }
+
+    // Chisholm (1983) B coefficient of a bend, tee or valve
+    fn chisholm_B(&self, fT: f64) -> Option<f64> {
+        // 90 degree bend, B90 = 1 + 2.2 / (K90 (2 + R/D)); other angles scale B - 1 by K90 / K
+        let bend = |RD: f64, LD90: f64, LD: f64| 1.0 + 2.2 / (fT * LD90 * (2.0 + RD)) * LD90 / LD;
+        match self {
+            Fitting::Elbow90 => Some(bend(1.0, 20.0, 20.0)),
+            Fitting::Elbow90LongRadius => Some(bend(1.5, 14.0, 14.0)),
+            Fitting::Elbow45 => Some(bend(1.0, 20.0, 16.0)),
+            Fitting::ReturnBend => Some(bend(1.0, 20.0, 50.0)),
+            Fitting::TeeRun | Fitting::TeeBranch => Some(1.75),
+            Fitting::GlobeValve | Fitting::LiftCheckValve => Some(2.3),
+            // straight-through bodies are taken as the gate valve
+            Fitting::GateValve
+            | Fitting::BallValve
+            | Fitting::PlugValve
+            | Fitting::ButterflyValve
+            | Fitting::SwingCheckValve => Some(1.5),
+            Fitting::Expansion { .. } | Fitting::Contraction { .. } => None,
+        }
+    }
+
+    // Loss through one fitting on the pipe of `input`, the upstream pipe of an area
+    // change. `model` applies to valves and area changes; bends and tees always use
+    // the Chisholm B coefficient.
+    pub fn loss(
+        &self,
+        input: &LineInput,
+        model: FittingModel,
+    ) -> Result<FittingResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let x = f.WG / (f.WL + f.WG); // vapor mass fraction [-]
+        let ULO = (f.WL + f.WG) / f.LoL / input.area() / 3600.0; // all-liquid velocity [m/s]
+        let dPLO = |K: f64| K * f.LoL * ULO.powi(2) / (2.0 * G) / 10000.0; // [kgf/cm^2]
+        let homogeneous = 1.0 + x * (f.LoL / f.LoG - 1.0);
+        let chisholm = |B: f64| 1.0 + (f.LoL / f.LoG - 1.0) * (B * x * (1.0 - x) + x.powi(2));
+        let momentum = || {
+            let alfa = 1.0 / (1.0 + (1.0 - x) / x * (f.LoG / f.LoL).powf(2.0 / 3.0)); // Zivi
+            (1.0 - x).powi(2) / (1.0 - alfa) + f.LoL / f.LoG * x.powi(2) / alfa
+        };
+        let area_phi2 = match model {
+            FittingModel::Homogeneous => homogeneous,
+            FittingModel::Separated => momentum(),
+        };
+
+        let (K, phi2) = match self {
+            Fitting::Expansion { pipe } => {
+                positive("expansion ID", pipe.ID)?;
+                in_range("expansion ID", pipe.ID, p.ID, f64::INFINITY)?;
+                let sigma = (p.ID / pipe.ID).powi(2); // upstream / downstream area [-]
+                (-2.0 * sigma * (1.0 - sigma), area_phi2)
+            }
+            Fitting::Contraction { pipe } => {
+                positive("contraction ID", pipe.ID)?;
+                in_range("contraction ID", pipe.ID, f64::MIN_POSITIVE, p.ID)?;
+                let sigma = (pipe.ID / p.ID).powi(2); // downstream / upstream area [-]
+                let Cc = 1.0 / (0.639 * (1.0 - sigma).sqrt() + 1.0); // vena contracta, Chisholm
+                let K2 = (1.0 / Cc - 1.0).powi(2) + 1.0 - sigma.powi(2); // on the downstream velocity
+                (K2 / sigma.powi(2), area_phi2)
+            }
+            fitting => {
+                let fT = input
+                    .friction
+                    .fanning(RE_ROUGH, p.rough / p.ID, input.solver)?
+                    * 4.0;
+                let K = fT * fitting.LD().unwrap_or_default();
+                let B = fitting.chisholm_B(fT).unwrap_or(1.0);
+                let valve = !matches!(
+                    fitting,
+                    Fitting::Elbow90
+                        | Fitting::Elbow90LongRadius
+                        | Fitting::Elbow45
+                        | Fitting::ReturnBend
+                        | Fitting::TeeRun
+                        | Fitting::TeeBranch
+                );
+                let phi2 = match model {
+                    FittingModel::Homogeneous if valve => homogeneous,
+                    _ => chisholm(B),
+                };
+                (K, phi2)
+            }
+        };
+        Ok(FittingResult {
+            fitting: self.clone(),
+            K,
+            phi2,
+            dP: dPLO(K) * phi2 * if K > 0.0 { input.SF } else { 1.0 },
+        })
+    }
+}
+
+// Total loss of a fittings list of (fitting, count) [kgf/cm^2]
+pub fn fittings_loss(
+    input: &LineInput,
+    fittings: &[(Fitting, usize)],
+    model: FittingModel,
+) -> Result<f64, TwoPhaseError> {
+    fittings.iter().try_fold(0.0, |sum, (fitting, count)| {
+        Ok(sum + fitting.loss(input, model)?.dP * *count as f64)
+    })
+}
+
+// Equivalent length of a fittings list of (fitting, count) on a pipe of inside
+// diameter ID [m], Crane L/D x ID [m]. Area changes have no L/D and add nothing; use
+// Fitting::loss for them.
+pub fn equivalent_length(fittings: &[(Fitting, usize)], ID: f64) -> f64 {
+    fittings
+        .iter()
+        .filter_map(|(fitting, count)| fitting.LD().map(|LD| LD * ID * *count as f64))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(SF: f64) -> LineInput {
+        LineInput::builder()
+            .liquid_mass_flow(64870.6) // [kg/hr]
+            .vapor_mass_flow(21623.5) // [kg/hr]
+            .liquid_density(790.99) // [kg/m^3]
+            .vapor_density(4.581) // [kg/m^3]
+            .liquid_viscosity(0.241) // [cP]
+            .vapor_viscosity(0.0091) // [cP]
+            .surface_tension(14.78) // [dyne/cm]
+            .pipe(PipeGeometry::from_nps("8", "40").unwrap())
+            .safety_factor(SF)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn safety_factor_scales_losses_not_recovery() {
+        let expansion = Fitting::Expansion {
+            pipe: PipeGeometry::from_nps("10", "40").unwrap(),
+        };
+        let model = FittingModel::Separated;
+        let recovery = expansion.loss(&input(1.0), model).unwrap();
+        assert!(recovery.K < 0.0 && recovery.dP < 0.0);
+        assert_eq!(expansion.loss(&input(1.5), model).unwrap().dP, recovery.dP);
+
+        let elbow = Fitting::Elbow90;
+        let loss = elbow.loss(&input(1.0), model).unwrap().dP;
+        let scaled = elbow.loss(&input(1.5), model).unwrap().dP;
+        assert!(loss > 0.0 && (scaled - 1.5 * loss).abs() < 1e-12);
+    }
+
+    #[test]
+    fn equivalent_length_of_a_fittings_count() {
+        let ID = 0.154051; // 6" SCH 40 [m]
+        let fittings = [
+            (Fitting::Elbow90, 4),    // 4 x 20 D
+            (Fitting::TeeBranch, 1),  // 60 D
+            (Fitting::GlobeValve, 2), // 2 x 340 D
+            (Fitting::GateValve, 0),  // none
+            (
+                Fitting::Contraction {
+                    pipe: PipeGeometry::from_nps("4", "40").unwrap(),
+                },
+                1,
+            ), // no L/D
+        ];
+        let length = equivalent_length(&fittings, ID);
+        assert!((length - 820.0 * ID).abs() < 1e-9);
+        assert_eq!(equivalent_length(&[], ID), 0.0);
+    }
+}
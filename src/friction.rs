@@ -0,0 +1,173 @@
+#![allow(non_snake_case)]
+
+// Single phase friction factor correlations, shared by every model.
+//
+// All return the Fanning friction factor (Darcy / 4) for a Reynolds number and a
+// relative roughness e = rough / ID. Below Re 2100 all but Churchill give the laminar
+// 16 / Re; Churchill (1977) covers laminar, transition and turbulent flow with one
+// continuous expression and is the choice near the transition.
+
+use crate::error::TwoPhaseError;
+use crate::solver::Solver;
+
+pub const LAMINAR_LIMIT: f64 = 2100.0; // [-]
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FrictionFactor {
+    // Colebrook-White (1939), implicit, solved by fixed point iteration
+    ColebrookWhite,
+    // Churchill (1977), all regimes
+    Churchill,
+    // Swamee and Jain (1976)
+    SwameeJain,
+    // Haaland (1983)
+    Haaland,
+    // Serghides (1984)
+    Serghides,
+    // Chen (1979)
+    #[default]
+    Chen,
+}
+
+impl FrictionFactor {
+    pub fn name(self) -> &'static str {
+        match self {
+            FrictionFactor::ColebrookWhite => "Colebrook-White",
+            FrictionFactor::Churchill => "Churchill (1977)",
+            FrictionFactor::SwameeJain => "Swamee-Jain (1976)",
+            FrictionFactor::Haaland => "Haaland (1983)",
+            FrictionFactor::Serghides => "Serghides (1984)",
+            FrictionFactor::Chen => "Chen (1979)",
+        }
+    }
+
+    // Fanning friction factor [-] at Reynolds number Re, relative roughness e [-];
+    // `solver` is used by the implicit Colebrook-White only, which reports a failed
+    // iteration as TwoPhaseError::NonConvergence
+    pub fn fanning(self, Re: f64, e: f64, solver: Solver) -> Result<f64, TwoPhaseError> {
+        if self != FrictionFactor::Churchill && Re < LAMINAR_LIMIT {
+            return Ok(16.0 / Re);
+        }
+        let f = match self {
+            FrictionFactor::ColebrookWhite => {
+                // x = 1 / sqrt(Darcy f), started from Swamee-Jain
+                let x0 = 1.0 / (4.0 * FrictionFactor::SwameeJain.fanning(Re, e, solver)?).sqrt();
+                let x = solver
+                    .fixed_point(
+                        "Colebrook-White: 1/sqrt(f)",
+                        |x| -2.0 * (e / 3.7 + 2.51 * x / Re).log10(),
+                        x0,
+                        1.0,
+                    )?
+                    .root;
+                0.25 / x.powi(2)
+            }
+            FrictionFactor::Churchill => {
+                let A = (2.457 * (1.0 / ((7.0 / Re).powf(0.9) + 0.27 * e)).ln()).powi(16);
+                let B = (37530.0 / Re).powi(16);
+                2.0 * ((8.0 / Re).powi(12) + 1.0 / (A + B).powf(1.5)).powf(1.0 / 12.0)
+            }
+            FrictionFactor::SwameeJain => 0.0625 / (e / 3.7 + 5.74 / Re.powf(0.9)).log10().powi(2),
+            FrictionFactor::Haaland => {
+                let x = -1.8 * ((e / 3.7).powf(1.11) + 6.9 / Re).log10();
+                0.25 / x.powi(2)
+            }
+            FrictionFactor::Serghides => {
+                let A = -2.0 * (e / 3.7 + 12.0 / Re).log10();
+                let B = -2.0 * (e / 3.7 + 2.51 * A / Re).log10();
+                let C = -2.0 * (e / 3.7 + 2.51 * B / Re).log10();
+                0.25 / (A - (B - A).powi(2) / (C - 2.0 * B + A)).powi(2)
+            }
+            FrictionFactor::Chen => {
+                let a = e.powf(1.1098) / 2.8257 + (7.149 / Re).powf(0.8961);
+                let b = -4.0 * ((e / 3.7065) - (5.0452 / Re) * (a.log10())).log10();
+                1.0 / b.powf(2.0)
+            }
+        };
+        Ok(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (Re, e, Colebrook-White Fanning f)
+    const POINTS: [(f64, f64, f64); 5] = [
+        (1.0e4, 0.0, 0.007720737588371924),
+        (1.0e5, 1.0e-4, 0.00462846651936791),
+        (1.0e6, 1.0e-3, 0.004985866460119216),
+        (1.0e7, 1.0e-5, 0.0022489279362086106),
+        (5.0e4, 1.0e-2, 0.009770411755174822),
+    ];
+
+    fn tight() -> Solver {
+        Solver::new(1e-12, 100)
+    }
+
+    #[test]
+    fn colebrook_white_reference_values() {
+        for (Re, e, f) in POINTS {
+            let cw = FrictionFactor::ColebrookWhite
+                .fanning(Re, e, tight())
+                .unwrap();
+            assert!((cw - f).abs() < 1e-12, "Re {} e {}: {} != {}", Re, e, cw, f);
+        }
+    }
+
+    #[test]
+    fn explicit_correlations_follow_colebrook_white() {
+        // largest relative deviation from Colebrook-White over POINTS [-]
+        let cases = [
+            (FrictionFactor::Churchill, 0.03),
+            (FrictionFactor::SwameeJain, 0.02),
+            (FrictionFactor::Haaland, 0.02),
+            (FrictionFactor::Serghides, 0.001),
+            (FrictionFactor::Chen, 0.005),
+        ];
+        for (correlation, tolerance) in cases {
+            for (Re, e, f) in POINTS {
+                let value = correlation.fanning(Re, e, tight()).unwrap();
+                assert!(
+                    ((value - f) / f).abs() < tolerance,
+                    "{} at Re {} e {}: {} vs {}",
+                    correlation.name(),
+                    Re,
+                    e,
+                    value,
+                    f
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn laminar_flow() {
+        for correlation in [
+            FrictionFactor::ColebrookWhite,
+            FrictionFactor::SwameeJain,
+            FrictionFactor::Haaland,
+            FrictionFactor::Serghides,
+            FrictionFactor::Chen,
+        ] {
+            assert_eq!(correlation.fanning(1000.0, 1e-4, tight()).unwrap(), 0.016);
+        }
+        let churchill = FrictionFactor::Churchill
+            .fanning(1000.0, 1e-4, tight())
+            .unwrap();
+        assert!((churchill - 0.016).abs() < 1e-4);
+    }
+
+    #[test]
+    fn colebrook_white_reports_non_convergence() {
+        let e = FrictionFactor::ColebrookWhite
+            .fanning(1.0e5, 1.0e-4, Solver::new(1e-15, 1))
+            .unwrap_err();
+        match e {
+            TwoPhaseError::NonConvergence {
+                solver, iterations, ..
+            } => assert_eq!((solver, iterations), ("Colebrook-White: 1/sqrt(f)", 1)),
+            other => panic!("expected NonConvergence, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+#![allow(non_snake_case)]
+
+// Separated-flow frictional pressure gradient correlations.
+//
+// These give the frictional gradient (and, where the method has one, the holdup) from
+// a two-phase multiplier on a single-phase gradient, without a flow regime map, so
+// they apply to any orientation. They are intended as a cross-check of the
+// mechanistic models; Pfric has the same units [kgf/cm^2/100m] and safety factor.
+
+use crate::error::TwoPhaseError;
+use crate::input::LineInput;
+
+const G: f64 = 9.81; // gravity accelerator [m/s^2]
+const GC: f64 = 9.8; // gravity constant [kg-m/kgf-s^2]
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frictional {
+    // Lockhart-Martinelli (1949), Chisholm (1967) C by liquid / vapor flow type
+    LockhartMartinelli,
+    // Chisholm (1973) B coefficient
+    ChisholmB,
+    // Friedel (1979)
+    Friedel,
+    // Muller-Steinhagen and Heck (1986)
+    MullerSteinhagenHeck,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrictionalResult {
+    pub method: Frictional,
+    pub Pfric: f64,          // Frictional Pressure Loss [kgf/cm^2/100m]
+    pub holdup: Option<f64>, // Liquid Volume Fraction [-], None if the method has no holdup
+    pub phi2: f64,           // two-phase multiplier on `reference` [-]
+    pub reference: f64,      // single-phase gradient the multiplier applies to [kgf/cm^2/100m]
+}
+
+// single phase frictional gradient of mass flow W [kg/hr] [kgf/cm^2/100m]
+fn single_phase(input: &LineInput, W: f64, Lo: f64, mu: f64) -> Result<(f64, f64), TwoPhaseError> {
+    let p = &input.pipe;
+    let U = W / Lo / input.area() / 3600.0; // velocity [m/s]
+    let Re = Lo * U * p.ID / mu;
+    let dP = 4.0 * input.fanning(Re)? * Lo * U.powi(2) / (2.0 * G * p.ID) / 10000.0 * 100.0;
+    Ok((dP, Re))
+}
+
+// total flow as liquid and as vapor: (vapor mass fraction [-], mass flux [kg/m^2-s],
+// liquid-only and vapor-only gradients [kgf/cm^2/100m])
+fn all_liquid_all_vapor(input: &LineInput) -> Result<(f64, f64, f64, f64), TwoPhaseError> {
+    let f = &input.fluid;
+    let W = f.WL + f.WG; // total mass flow [kg/hr]
+    let (dPLO, _) = single_phase(input, W, f.LoL, f.muL)?;
+    let (dPGO, _) = single_phase(input, W, f.LoG, f.muG)?;
+    Ok((f.WG / W, W / input.area() / 3600.0, dPLO, dPGO))
+}
+
+impl Frictional {
+    pub fn name(self) -> &'static str {
+        match self {
+            Frictional::LockhartMartinelli => "Lockhart-Martinelli",
+            Frictional::ChisholmB => "Chisholm B",
+            Frictional::Friedel => "Friedel",
+            Frictional::MullerSteinhagenHeck => "Muller-Steinhagen-Heck",
+        }
+    }
+
+    pub fn gradient(self, input: &LineInput) -> Result<FrictionalResult, TwoPhaseError> {
+        let result = match self {
+            Frictional::LockhartMartinelli => lockhart_martinelli(input)?,
+            Frictional::ChisholmB => chisholm_b(input)?,
+            Frictional::Friedel => friedel(input)?,
+            Frictional::MullerSteinhagenHeck => muller_steinhagen_heck(input)?,
+        };
+        if let Some(holdup) = result.holdup {
+            TwoPhaseError::check_range("holdup", holdup, 0.0, 1.0)?;
+        }
+        TwoPhaseError::check_range("Pfric", result.Pfric, 0.0, f64::MAX)?;
+        Ok(result)
+    }
+}
+
+fn lockhart_martinelli(input: &LineInput) -> Result<FrictionalResult, TwoPhaseError> {
+    let f = &input.fluid;
+    let (dPL, ReL) = single_phase(input, f.WL, f.LoL, f.muL)?; // liquid alone
+    let (dPG, ReG) = single_phase(input, f.WG, f.LoG, f.muG)?; // vapor alone
+    let X = (dPL / dPG).sqrt(); // Martinelli parameter [-]
+
+    // first letter liquid, second vapor; t = turbulent, v = viscous (laminar)
+    let C = match (ReL >= 2100.0, ReG >= 2100.0) {
+        (true, true) => 20.0,
+        (false, true) => 12.0,
+        (true, false) => 10.0,
+        (false, false) => 5.0,
+    };
+    let phi2 = 1.0 + C / X + 1.0 / X.powi(2); // Chisholm (1967)
+    let alfa = (1.0 + X.powf(0.8)).powf(-0.378); // void fraction, Wallis (1969)
+    Ok(FrictionalResult {
+        method: Frictional::LockhartMartinelli,
+        Pfric: phi2 * dPL * input.SF,
+        holdup: Some(1.0 - alfa),
+        phi2,
+        reference: dPL,
+    })
+}
+
+fn chisholm_b(input: &LineInput) -> Result<FrictionalResult, TwoPhaseError> {
+    let f = &input.fluid;
+    let (x, Gm, dPLO, dPGO) = all_liquid_all_vapor(input)?;
+    let Gama = (dPGO / dPLO).sqrt();
+    let B = if Gama <= 9.5 {
+        if Gm <= 500.0 {
+            4.8
+        } else if Gm < 1900.0 {
+            2400.0 / Gm
+        } else {
+            55.0 / Gm.sqrt()
+        }
+    } else if Gama < 28.0 {
+        if Gm <= 600.0 {
+            520.0 / (Gama * Gm.sqrt())
+        } else {
+            21.0 / Gama
+        }
+    } else {
+        15000.0 / (Gama.powi(2) * Gm.sqrt())
+    };
+    let n = 0.25; // Blasius exponent
+    let phi2 =
+        1.0 + (Gama.powi(2) - 1.0) * (B * (x * (1.0 - x)).powf((2.0 - n) / 2.0) + x.powf(2.0 - n));
+    // slip ratio S = [1 - x (1 - LoL / LoG)]^0.5, Chisholm (1972)
+    let S = (1.0 - x * (1.0 - f.LoL / f.LoG)).sqrt();
+    let alfa = 1.0 / (1.0 + S * (1.0 - x) / x * f.LoG / f.LoL);
+    Ok(FrictionalResult {
+        method: Frictional::ChisholmB,
+        Pfric: phi2 * dPLO * input.SF,
+        holdup: Some(1.0 - alfa),
+        phi2,
+        reference: dPLO,
+    })
+}
+
+fn friedel(input: &LineInput) -> Result<FrictionalResult, TwoPhaseError> {
+    let f = &input.fluid;
+    let p = &input.pipe;
+    let (x, Gm, dPLO, _) = all_liquid_all_vapor(input)?;
+    let sigma = f.ST * GC; // surface tension [N/m]
+    let fLO = input.fanning(Gm * p.ID / f.muL)?;
+    let fGO = input.fanning(Gm * p.ID / f.muG)?;
+    let LoH = 1.0 / (x / f.LoG + (1.0 - x) / f.LoL); // homogeneous density [kg/m^3]
+    let Fr = Gm.powi(2) / (G * p.ID * LoH.powi(2));
+    let We = Gm.powi(2) * p.ID / (sigma * LoH);
+    let E = (1.0 - x).powi(2) + x.powi(2) * f.LoL * fGO / (f.LoG * fLO);
+    let F = x.powf(0.78) * (1.0 - x).powf(0.224);
+    let H =
+        (f.LoL / f.LoG).powf(0.91) * (f.muG / f.muL).powf(0.19) * (1.0 - f.muG / f.muL).powf(0.7);
+    let phi2 = E + 3.24 * F * H / (Fr.powf(0.045) * We.powf(0.035));
+    Ok(FrictionalResult {
+        method: Frictional::Friedel,
+        Pfric: phi2 * dPLO * input.SF,
+        holdup: None,
+        phi2,
+        reference: dPLO,
+    })
+}
+
+fn muller_steinhagen_heck(input: &LineInput) -> Result<FrictionalResult, TwoPhaseError> {
+    let (x, _, dPLO, dPGO) = all_liquid_all_vapor(input)?;
+    let Lamda = dPLO + 2.0 * (dPGO - dPLO) * x;
+    let dP = Lamda * (1.0 - x).powf(1.0 / 3.0) + dPGO * x.powi(3);
+    Ok(FrictionalResult {
+        method: Frictional::MullerSteinhagenHeck,
+        Pfric: dP * input.SF,
+        holdup: None,
+        phi2: dP / dPLO,
+        reference: dPLO,
+    })
+}
@@ -0,0 +1,153 @@
+#![allow(non_snake_case)]
+
+// Modified Hagedorn and Brown (1965) correlation for vertical upflow, as given by
+// Brill and Mukherjee (1999), "Multiphase Flow in Wells", SPE Monograph 17:
+//   - Griffith and Wallis (1961) bubble flow when the gas fraction is below LB
+//   - otherwise the Hagedorn-Brown holdup charts (curve fits of CNL, HL/psi and psi),
+//     with the holdup kept above the no-slip value
+// The holdup chart depends on the absolute pressure, which LineInput does not carry;
+// it is a required argument of the method. The holdup takes the pipe as vertical;
+// only the elevation term follows the inclination.
+
+use crate::error::TwoPhaseError;
+use crate::input::{positive, LineInput};
+use crate::result::{LineResult, RegimeDetail};
+use crate::twoline::{Regime, RegimeReport, TwoPhaseLine};
+
+const G: f64 = 9.81; // gravity accelerator [m/s^2]
+const GC: f64 = 9.8; // gravity constant [kg-m/kgf-s^2]
+
+pub const ATMOSPHERE: f64 = 1.0332; // [kgf/cm^2 abs]
+const US_GRIFFITH: f64 = 0.244; // bubble slip velocity, 0.8 ft/s [m/s]
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HagedornBrown {
+    pub pressure: f64, // line pressure for the holdup chart [kgf/cm^2 abs]
+}
+
+impl HagedornBrown {
+    // line pressure P [kgf/cm^2 abs] for the holdup chart
+    pub fn new(pressure: f64) -> Self {
+        HagedornBrown { pressure }
+    }
+
+    // Griffith bubble flow, slip velocity 0.8 ft/s
+    fn BubbleModel(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let Landa = ULS / UM; // no-slip holdup [-]
+        let r = 1.0 + UM / US_GRIFFITH;
+        let HL = 1.0 - 0.5 * (r - (r.powi(2) - 4.0 * UGS / US_GRIFFITH).sqrt());
+        let UL = ULS / HL; // Liquid Velocity [m/s]
+        let fTP = input.fanning(f.LoL * UL * p.ID / f.muL)? * 4.0;
+        let Pfric = fTP * f.LoL * UL.powi(2) / (2.0 * G * p.ID) / 10000.0 * 100.0 * input.SF;
+        let density = f.LoL * HL + f.LoG * (1.0 - HL);
+        let LoNS = f.LoL * Landa + f.LoG * (1.0 - Landa); // no-slip density [kg/m^3]
+        Ok(LineResult {
+            regime,
+            holdup: HL,
+            density,
+            velocity: UM,
+            Pfric,
+            Pgrav: density * p.rise_per_length() / 10000.0 * 100.0,
+            Pacc: 0.0,
+            Head: LoNS * UM.powi(2) / (2.0 * G) / 10000.0,
+            Ef: (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0, // must transfer to imperial unit
+            detail: Some(RegimeDetail::phase_velocities(ULS, UGS, HL)),
+            blend: None,
+            fallback: None,
+        })
+    }
+
+    fn Model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let sigma = f.ST * GC; // surface tension [N/m]
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let Landa = ULS / UM; // no-slip holdup [-]
+
+        // dimensionless numbers of Duns and Ros
+        let k = (f.LoL / (G * sigma)).powf(0.25);
+        let NLV = ULS * k; // liquid velocity number
+        let NGV = UGS * k; // gas velocity number
+        let ND = p.ID * (f.LoL * G / sigma).sqrt(); // pipe diameter number
+        let NL = f.muL * (G / (f.LoL * sigma.powi(3))).powf(0.25); // liquid viscosity number
+
+        let CNL = (0.0019 + 0.0322 * NL - 0.6642 * NL.powi(2) + 4.9951 * NL.powi(3))
+            / (1.0 - 10.0147 * NL + 33.8696 * NL.powi(2) + 277.2817 * NL.powi(3));
+        let X1 = NLV / NGV.powf(0.575) * (self.pressure / ATMOSPHERE).powf(0.1) * CNL / ND;
+        let HLPsi = ((0.0047 + 1123.32 * X1 + 729489.64 * X1.powi(2))
+            / (1.0 + 1097.1566 * X1 + 722153.97 * X1.powi(2)))
+        .sqrt();
+        let X2 = (NGV * NL.powf(0.38) / ND.powf(2.14)).min(0.09);
+        let Psi = if X2 <= 0.01 {
+            1.0
+        } else {
+            (1.0886 - 69.9473 * X2 + 2334.3497 * X2.powi(2) - 12896.683 * X2.powi(3))
+                / (1.0 - 53.4401 * X2 + 1517.9369 * X2.powi(2) - 8419.8115 * X2.powi(3))
+        };
+        let HL = (HLPsi * Psi).max(Landa).min(1.0);
+
+        let density = f.LoL * HL + f.LoG * (1.0 - HL); // slip density [kg/m^3]
+        let LoNS = f.LoL * Landa + f.LoG * (1.0 - Landa); // no-slip density [kg/m^3]
+        let muS = f.muL.powf(HL) * f.muG.powf(1.0 - HL); // slip viscosity [kg/m-s]
+        let fTP = input.fanning(LoNS * UM * p.ID / muS)? * 4.0;
+        let Pfric = fTP * LoNS.powi(2) * UM.powi(2) / (2.0 * G * density * p.ID) / 10000.0
+            * 100.0
+            * input.SF;
+        Ok(LineResult {
+            regime,
+            holdup: HL,
+            density,
+            velocity: UM,
+            Pfric,
+            Pgrav: density * p.rise_per_length() / 10000.0 * 100.0,
+            Pacc: 0.0,
+            Head: LoNS * UM.powi(2) / (2.0 * G) / 10000.0,
+            Ef: (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0, // must transfer to imperial unit
+            detail: Some(RegimeDetail::phase_velocities(ULS, UGS, HL)),
+            blend: None,
+            fallback: None,
+        })
+    }
+
+    // regime from the transition ratios, in the order given to RegimeReport::new
+    fn classify(r: &[f64]) -> Regime {
+        if r[0] < 1.0 {
+            Regime::HagedornBrownBubbleFlow(String::from("Griffith Bubble Flow"))
+        } else {
+            Regime::HagedornBrownFlow(String::from("Hagedorn-Brown Flow"))
+        }
+    }
+}
+
+impl TwoPhaseLine for HagedornBrown {
+    fn method(&self) -> &'static str {
+        "Hagedorn-Brown (1965) modified, vertical upflow"
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS;
+        // Griffith bubble flow limit on the gas fraction, 1.071 - 0.2218 UM^2 / D in ft/s, ft
+        let LB = (1.071 - 0.2218 * 3.28084 * UM.powi(2) / input.pipe.ID).max(0.13);
+        Ok(RegimeReport::new(
+            self.method(),
+            &[("LB", UGS / UM / LB)],
+            HagedornBrown::classify,
+        ))
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        positive("pressure", self.pressure)?;
+        let result = match regime {
+            Regime::HagedornBrownBubbleFlow(..) => self.BubbleModel(input, regime),
+            Regime::HagedornBrownFlow(..) => self.Model(input, regime),
+            _ => Err(TwoPhaseError::UnsupportedRegime(regime)),
+        }?;
+        result.checked()
+    }
+}
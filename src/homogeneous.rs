@@ -0,0 +1,152 @@
+#![allow(non_snake_case)]
+
+// Homogeneous equilibrium model (HEM): both phases at the mixture velocity with no
+// slip, so the holdup is the no-slip liquid fraction and the friction factor is the
+// single phase one at the mixture Reynolds number, for a selectable mixture viscosity.
+// It has no regime map and applies to any inclination.
+//
+// `Fallback` wraps a mechanistic model and returns the HEM result, with the failure
+// recorded in `LineResult::fallback`, when that model does not converge to a
+// physical solution.
+
+use crate::error::TwoPhaseError;
+use crate::input::LineInput;
+use crate::result::{Failure, LineResult, RegimeDetail};
+use crate::twoline::{Regime, RegimeReport, TwoPhaseLine};
+
+const G: f64 = 9.81; // gravity accelerator [m/s^2]
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MixtureViscosity {
+    // 1 / mu = x / muG + (1 - x) / muL
+    #[default]
+    McAdams,
+    // mu = x muG + (1 - x) muL
+    Cicchitti,
+    // mu = Landa muL + (1 - Landa) muG, on the no-slip volume fraction
+    Dukler,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Homogeneous {
+    pub viscosity: MixtureViscosity,
+}
+
+impl Homogeneous {
+    pub fn new() -> Self {
+        Homogeneous::default()
+    }
+
+    pub fn viscosity(mut self, viscosity: MixtureViscosity) -> Self {
+        self.viscosity = viscosity;
+        self
+    }
+
+    fn Model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let f = &input.fluid;
+        let p = &input.pipe;
+        let (UGS, ULS) = input.superficial_velocities();
+        let UM = UGS + ULS; // Mixture Velocity [m/s]
+        let Landa = ULS / UM; // no-slip holdup [-]
+        let x = f.WG / (f.WL + f.WG); // vapor mass fraction [-]
+
+        let LoNS = f.LoL * Landa + f.LoG * (1.0 - Landa); // no-slip density [kg/m^3]
+        let muNS = match self.viscosity {
+            MixtureViscosity::McAdams => 1.0 / (x / f.muG + (1.0 - x) / f.muL),
+            MixtureViscosity::Cicchitti => x * f.muG + (1.0 - x) * f.muL,
+            MixtureViscosity::Dukler => Landa * f.muL + (1.0 - Landa) * f.muG,
+        }; // mixture viscosity [kg/m-s]
+        let fTP = input.fanning(LoNS * UM * p.ID / muNS)? * 4.0;
+        let Pfric = fTP * LoNS * UM.powi(2) / (2.0 * G * p.ID) / 10000.0 * 100.0 * input.SF;
+        Ok(LineResult {
+            regime,
+            holdup: Landa,
+            density: LoNS,
+            velocity: UM,
+            Pfric,
+            Pgrav: LoNS * p.rise_per_length() / 10000.0 * 100.0,
+            Pacc: 0.0,
+            Head: LoNS * UM.powi(2) / (2.0 * G) / 10000.0,
+            Ef: (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0, // must transfer to imperial unit
+            detail: Some(RegimeDetail::phase_velocities(ULS, UGS, Landa)),
+            blend: None,
+            fallback: None,
+        })
+    }
+
+    fn classify(_: &[f64]) -> Regime {
+        Regime::HomogeneousFlow(String::from("Homogeneous Flow"))
+    }
+}
+
+impl TwoPhaseLine for Homogeneous {
+    fn method(&self) -> &'static str {
+        "Homogeneous equilibrium model"
+    }
+
+    fn regime(&self, _: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        Ok(RegimeReport::new(self.method(), &[], Homogeneous::classify))
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        let result = match regime {
+            Regime::HomogeneousFlow(..) => self.Model(input, regime),
+            _ => Err(TwoPhaseError::UnsupportedRegime(regime)),
+        }?;
+        result.checked()
+    }
+}
+
+// A model that falls back to the homogeneous equilibrium model when its regime map or
+// model fails to converge (NonConvergence, NotBracketed) or converges outside the
+// valid range (OutOfRange). Input and unsupported regime errors are returned as is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fallback<L> {
+    pub line: L,
+    pub homogeneous: Homogeneous,
+}
+
+impl<L: TwoPhaseLine> Fallback<L> {
+    pub fn new(line: L) -> Self {
+        Fallback {
+            line,
+            homogeneous: Homogeneous::default(),
+        }
+    }
+
+    pub fn homogeneous(mut self, homogeneous: Homogeneous) -> Self {
+        self.homogeneous = homogeneous;
+        self
+    }
+}
+
+impl<L: TwoPhaseLine> TwoPhaseLine for Fallback<L> {
+    fn method(&self) -> &'static str {
+        self.line.method()
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        self.line.regime(input)
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        self.line.model(input, regime)
+    }
+
+    fn gradient(&self, input: &LineInput) -> Result<LineResult, TwoPhaseError> {
+        match self.line.gradient(input) {
+            Err(
+                error @ (TwoPhaseError::NonConvergence { .. }
+                | TwoPhaseError::NotBracketed { .. }
+                | TwoPhaseError::OutOfRange { .. }),
+            ) => Ok(LineResult {
+                fallback: Some(Failure {
+                    method: self.line.method(),
+                    error,
+                }),
+                ..self.homogeneous.gradient(input)?
+            }),
+            result => result,
+        }
+    }
+}
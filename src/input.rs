@@ -0,0 +1,405 @@
+#![allow(non_snake_case)]
+
+use std::fmt;
+
+use crate::error::TwoPhaseError;
+use crate::friction::FrictionFactor;
+use crate::schedule::{self, Material};
+use crate::solver::Solver;
+use crate::units::{Quantity, UnitSystem};
+
+pub const DEFAULT_ROUGHNESS: f64 = 4.572e-5; // commercial steel absolute roughness [m]
+pub const DEFAULT_SF: f64 = 1.0; // Safety factor [-]
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FluidStreams {
+    pub WL: f64,  // liquid mass flow rate [kg/hr]
+    pub WG: f64,  // Vapor mass flow rate [kg/hr]
+    pub LoL: f64, // Liquid density [kg/m^3]
+    pub LoG: f64, // Vapor density [kg/m^3]
+    pub muL: f64, // Liquid viscosity [kg/m-s]
+    pub muG: f64, // Vapor viscosity [kg/m-s]
+    pub ST: f64,  // Liquid surface tension [kgf/m]
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipeGeometry {
+    pub ID: f64,     // pipe inside diameter [m]
+    pub rough: f64,  // pipe absolute roughness [m]
+    pub degree: f64, // degree,  Horizontal = 0, -Up / +Down
+}
+
+impl PipeGeometry {
+    // horizontal commercial steel pipe of the given inside diameter [m]
+    pub fn new(ID: f64) -> Self {
+        PipeGeometry {
+            ID,
+            rough: DEFAULT_ROUGHNESS,
+            degree: 0.0,
+        }
+    }
+
+    // horizontal carbon steel pipe of a standard size, e.g. from_nps("6", "40")
+    pub fn from_nps(nps: &str, schedule: &str) -> Result<Self, InputError> {
+        Ok(PipeGeometry::new(schedule::lookup(nps, schedule)?.ID()))
+    }
+
+    // the default absolute roughness of the pipe material
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.rough = material.roughness();
+        self
+    }
+
+    // inclination [rad]
+    pub fn theta(&self) -> f64 {
+        self.degree.to_radians()
+    }
+
+    // sine of the inclination, rise per unit length, positive upward [-]
+    pub fn rise_per_length(&self) -> f64 {
+        (-self.theta()).sin() + 0.0 // + 0.0 turns the -0 of a horizontal pipe into 0
+    }
+}
+
+// One line case: the same value can be evaluated by every orientation model.
+// All fields hold the internal units listed in `units`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineInput {
+    pub fluid: FluidStreams,
+    pub pipe: PipeGeometry,
+    pub SF: f64,                  // Safety factor [-]
+    pub friction: FrictionFactor, // single phase friction factor correlation
+    pub solver: Solver,           // tolerance and iteration cap of the models' solvers
+}
+
+impl LineInput {
+    pub fn new(fluid: FluidStreams, pipe: PipeGeometry, SF: f64) -> Result<Self, InputError> {
+        let input = LineInput {
+            fluid,
+            pipe,
+            SF,
+            friction: FrictionFactor::default(),
+            solver: Solver::default(),
+        };
+        input.validate()?;
+        Ok(input)
+    }
+
+    pub fn builder() -> LineInputBuilder {
+        LineInputBuilder::default()
+    }
+
+    // single phase Fanning friction factor of this pipe at Reynolds number Re [-]
+    pub fn fanning(&self, Re: f64) -> Result<f64, TwoPhaseError> {
+        self.friction
+            .fanning(Re, self.pipe.rough / self.pipe.ID, self.solver)
+    }
+
+    // pipe inside cross section area [m^2]
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.pipe.ID * self.pipe.ID / 4.0
+    }
+
+    // (UGS, ULS) Vapor and Liquid Superficial Velocity [m/s]
+    pub fn superficial_velocities(&self) -> (f64, f64) {
+        let area = self.area();
+        (
+            self.fluid.WG / self.fluid.LoG / area / 3600.0,
+            self.fluid.WL / self.fluid.LoL / area / 3600.0,
+        )
+    }
+
+    // the same line with the mass flows set from superficial velocities [m/s]
+    pub fn with_superficial_velocities(&self, UGS: f64, ULS: f64) -> LineInput {
+        let area = self.area();
+        let mut input = self.clone();
+        input.fluid.WG = UGS * self.fluid.LoG * area * 3600.0;
+        input.fluid.WL = ULS * self.fluid.LoL * area * 3600.0;
+        input
+    }
+
+    pub fn validate(&self) -> Result<(), InputError> {
+        let f = &self.fluid;
+        let p = &self.pipe;
+        positive("WL", f.WL)?;
+        positive("WG", f.WG)?;
+        positive("LoL", f.LoL)?;
+        positive("LoG", f.LoG)?;
+        positive("muL", f.muL)?;
+        positive("muG", f.muG)?;
+        positive("ST", f.ST)?;
+        positive("ID", p.ID)?;
+        positive("SF", self.SF)?;
+        positive("solver tol", self.solver.tol)?;
+        finite("rough", p.rough)?;
+        finite("degree", p.degree)?;
+        if f.LoL <= f.LoG {
+            return Err(InputError::DensityInversion {
+                LoL: f.LoL,
+                LoG: f.LoG,
+            });
+        }
+        in_range("rough", p.rough, 0.0, p.ID)?;
+        in_range("degree", p.degree, -90.0, 90.0)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputError {
+    Missing(&'static str),
+    NotFinite {
+        field: &'static str,
+        value: f64,
+    },
+    NotPositive {
+        field: &'static str,
+        value: f64,
+    },
+    OutOfRange {
+        field: &'static str,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+    DensityInversion {
+        LoL: f64,
+        LoG: f64,
+    },
+    // no such size in the pipe schedule table
+    UnknownPipeSize {
+        nps: String,
+        schedule: String,
+    },
+    // the input inclination is not the one a Dispatch was selected for
+    InclinationMismatch {
+        selected: f64,
+        degree: f64,
+    },
+    // a survey point that cannot be read, line counted from 1
+    Survey {
+        line: usize,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(field) => write!(f, "input `{}` is required", field),
+            InputError::NotFinite { field, value } => {
+                write!(f, "input `{}` = {} is not a finite number", field, value)
+            }
+            InputError::NotPositive { field, value } => {
+                write!(f, "input `{}` = {} must be greater than zero", field, value)
+            }
+            InputError::OutOfRange {
+                field,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "input `{}` = {} is outside the range [{}, {}]",
+                field, value, min, max
+            ),
+            InputError::DensityInversion { LoL, LoG } => write!(
+                f,
+                "liquid density {} must be greater than vapor density {}",
+                LoL, LoG
+            ),
+            InputError::UnknownPipeSize { nps, schedule } => write!(
+                f,
+                "NPS {} schedule {} is not in the pipe schedule table",
+                nps, schedule
+            ),
+            InputError::InclinationMismatch { selected, degree } => write!(
+                f,
+                "input `degree` = {} differs from the inclination {} the model was selected for",
+                degree, selected
+            ),
+            InputError::Survey { line, reason } => write!(f, "survey line {}: {}", line, reason),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub(crate) fn finite(field: &'static str, value: f64) -> Result<(), InputError> {
+    if value.is_finite() {
+        Ok(())
+    } else {
+        Err(InputError::NotFinite { field, value })
+    }
+}
+
+pub(crate) fn positive(field: &'static str, value: f64) -> Result<(), InputError> {
+    finite(field, value)?;
+    if value > 0.0 {
+        Ok(())
+    } else {
+        Err(InputError::NotPositive { field, value })
+    }
+}
+
+pub(crate) fn in_range(
+    field: &'static str,
+    value: f64,
+    min: f64,
+    max: f64,
+) -> Result<(), InputError> {
+    if value < min || value > max {
+        Err(InputError::OutOfRange {
+            field,
+            value,
+            min,
+            max,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+// Collects the inputs in the chosen unit system (MetricEngineering by default) and
+// converts them to internal units on build().
+#[derive(Debug, Clone, Default)]
+pub struct LineInputBuilder {
+    units: UnitSystem,
+    fluid: Option<FluidStreams>,
+    pipe: Option<PipeGeometry>,
+    WL: Option<f64>,
+    WG: Option<f64>,
+    LoL: Option<f64>,
+    LoG: Option<f64>,
+    muL: Option<f64>,
+    muG: Option<f64>,
+    ST: Option<f64>,
+    ID: Option<f64>,
+    rough: Option<f64>,
+    degree: Option<f64>,
+    SF: Option<f64>,
+    friction: Option<FrictionFactor>,
+    solver: Option<Solver>,
+}
+
+impl LineInputBuilder {
+    pub fn units(mut self, units: UnitSystem) -> Self {
+        self.units = units;
+        self
+    }
+
+    pub fn liquid_mass_flow(mut self, WL: f64) -> Self {
+        self.WL = Some(WL);
+        self
+    }
+
+    pub fn vapor_mass_flow(mut self, WG: f64) -> Self {
+        self.WG = Some(WG);
+        self
+    }
+
+    pub fn liquid_density(mut self, LoL: f64) -> Self {
+        self.LoL = Some(LoL);
+        self
+    }
+
+    pub fn vapor_density(mut self, LoG: f64) -> Self {
+        self.LoG = Some(LoG);
+        self
+    }
+
+    pub fn liquid_viscosity(mut self, muL: f64) -> Self {
+        self.muL = Some(muL);
+        self
+    }
+
+    pub fn vapor_viscosity(mut self, muG: f64) -> Self {
+        self.muG = Some(muG);
+        self
+    }
+
+    pub fn surface_tension(mut self, ST: f64) -> Self {
+        self.ST = Some(ST);
+        self
+    }
+
+    pub fn inside_diameter(mut self, ID: f64) -> Self {
+        self.ID = Some(ID);
+        self
+    }
+
+    pub fn roughness(mut self, rough: f64) -> Self {
+        self.rough = Some(rough);
+        self
+    }
+
+    // [degree], Horizontal = 0, -Up / +Down
+    pub fn inclination(mut self, degree: f64) -> Self {
+        self.degree = Some(degree);
+        self
+    }
+
+    pub fn safety_factor(mut self, SF: f64) -> Self {
+        self.SF = Some(SF);
+        self
+    }
+
+    // single phase friction factor correlation, Chen (1979) by default
+    pub fn friction(mut self, friction: FrictionFactor) -> Self {
+        self.friction = Some(friction);
+        self
+    }
+
+    // tolerance and iteration cap of the models' solvers, Solver::default() by default
+    pub fn solver(mut self, solver: Solver) -> Self {
+        self.solver = Some(solver);
+        self
+    }
+
+    // all fluid properties at once, already in internal units
+    pub fn fluid(mut self, fluid: FluidStreams) -> Self {
+        self.fluid = Some(fluid);
+        self
+    }
+
+    // inside diameter, roughness and inclination at once, already in internal units
+    pub fn pipe(mut self, pipe: PipeGeometry) -> Self {
+        self.pipe = Some(pipe);
+        self
+    }
+
+    pub fn build(self) -> Result<LineInput, InputError> {
+        let u = self.units;
+        // an individual setter overrides the value given through fluid() / pipe()
+        let value = |v: Option<f64>, q: Quantity, base: Option<f64>, field: &'static str| match v {
+            Some(v) => Ok(u.to_internal(q, v)),
+            None => base.ok_or(InputError::Missing(field)),
+        };
+        let f = self.fluid.as_ref();
+        let fluid = FluidStreams {
+            WL: value(self.WL, Quantity::MassFlow, f.map(|f| f.WL), "WL")?,
+            WG: value(self.WG, Quantity::MassFlow, f.map(|f| f.WG), "WG")?,
+            LoL: value(self.LoL, Quantity::Density, f.map(|f| f.LoL), "LoL")?,
+            LoG: value(self.LoG, Quantity::Density, f.map(|f| f.LoG), "LoG")?,
+            muL: value(self.muL, Quantity::Viscosity, f.map(|f| f.muL), "muL")?,
+            muG: value(self.muG, Quantity::Viscosity, f.map(|f| f.muG), "muG")?,
+            ST: value(self.ST, Quantity::SurfaceTension, f.map(|f| f.ST), "ST")?,
+        };
+        let p = self.pipe.as_ref();
+        let pipe = PipeGeometry {
+            ID: value(self.ID, Quantity::Diameter, p.map(|p| p.ID), "ID")?,
+            rough: value(
+                self.rough,
+                Quantity::Roughness,
+                Some(p.map_or(DEFAULT_ROUGHNESS, |p| p.rough)),
+                "rough",
+            )?,
+            degree: self.degree.or(p.map(|p| p.degree)).unwrap_or(0.0),
+        };
+        let mut input = LineInput::new(fluid, pipe, self.SF.unwrap_or(DEFAULT_SF))?;
+        input.friction = self.friction.unwrap_or_default();
+        input.solver = self.solver.unwrap_or_default();
+        input.validate()?;
+        Ok(input)
+    }
+}
@@ -0,0 +1,120 @@
+//! Two-phase (vapor-liquid) line calculation engine.
+//!
+//! Flow regime prediction and pressure-drop models for horizontal, vertical-up
+//! and vertical-down pipes, and a unified map for any inclination in between.
+//! The public API is the set of items re-exported from the crate root and
+//! [`prelude`]; it follows semantic versioning, so while the crate is at `0.x` a
+//! minor version bump may contain breaking changes and a patch bump never does.
+//!
+//! Inputs are given in a [`UnitSystem`] (metric-engineering by default) and held
+//! in fixed internal units from then on; see [`units`].
+//!
+//! ```no_run
+//! use twophase::prelude::*;
+//!
+//! let input = LineInput::builder()
+//!     .liquid_mass_flow(64870.6) // [kg/hr]
+//!     .vapor_mass_flow(21623.5) // [kg/hr]
+//!     .liquid_density(790.99) // [kg/m^3]
+//!     .vapor_density(4.581) // [kg/m^3]
+//!     .liquid_viscosity(0.241) // [cP]
+//!     .vapor_viscosity(0.0091) // [cP]
+//!     .surface_tension(14.78) // [dyne/cm]
+//!     .inside_diameter(202.72) // [mm]
+//!     .inclination(-90.0) // [degree], riser
+//!     .build()?;
+//!
+//! let result = VerticalUp.gradient(&input)?;
+//! println!("{} : {:.4} kgf/cm^2/100m", result.regime, result.Pfric);
+//!
+//! let us = UnitSystem::USCustomary;
+//! let grad = us.from_internal(Quantity::PressureGradient, result.Pfric);
+//! println!("{:.4} {}", grad, us.label(Quantity::PressureGradient));
+//!
+//! // or let the inclination pick the regime map
+//! let line = <dyn TwoPhaseLine>::for_inclination(-90.0);
+//! let report = line.regime(&input)?;
+//! println!("{} ({})", report.regime, report.method);
+//! # Ok::<(), twophase::TwoPhaseError>(())
+//! ```
+
+pub mod ansari;
+pub mod barnea;
+pub mod beggs_brill;
+pub mod blend;
+pub mod dispatch;
+pub mod duns_ros;
+pub mod error;
+pub mod fittings;
+pub mod friction;
+pub mod frictional;
+pub mod hagedorn_brown;
+pub mod homogeneous;
+pub mod horizontal;
+pub mod input;
+pub mod pipeline;
+pub mod regime_map;
+pub mod result;
+pub mod schedule;
+pub mod sizing;
+pub mod solver;
+pub mod survey;
+pub mod twoline;
+pub mod units;
+pub mod vertical_down;
+pub mod vertical_up;
+
+pub use ansari::Ansari;
+pub use barnea::Barnea;
+pub use beggs_brill::BeggsBrill;
+pub use blend::Blended;
+pub use dispatch::{Dispatch, Orientation};
+pub use duns_ros::DunsRos;
+pub use error::TwoPhaseError;
+pub use fittings::{equivalent_length, fittings_loss, Fitting, FittingModel, FittingResult};
+pub use friction::FrictionFactor;
+pub use frictional::{Frictional, FrictionalResult};
+pub use hagedorn_brown::HagedornBrown;
+pub use homogeneous::{Fallback, Homogeneous, MixtureViscosity};
+pub use horizontal::Horizontal;
+pub use input::{FluidStreams, InputError, LineInput, LineInputBuilder, PipeGeometry};
+pub use pipeline::{GasLaw, Pipeline, Profile, ProfilePoint, Segment};
+pub use regime_map::{regime_map, Boundary, MapOptions, RegimeMap};
+pub use result::{Blend, Failure, LineResult, RegimeDetail};
+pub use schedule::{Material, PipeSize};
+pub use sizing::{size_line, Rejection, SizeCase, Sizing, SizingCriteria};
+pub use solver::{Solution, Solver};
+pub use survey::Survey;
+pub use twoline::{FlowPattern, Regime, RegimeReport, Transition, TwoPhaseLine};
+pub use units::{Quantity, UnitSystem};
+pub use vertical_down::VerticalDown;
+pub use vertical_up::VerticalUp;
+
+/// Glob import for the common types: `use twophase::prelude::*;`
+pub mod prelude {
+    pub use crate::ansari::Ansari;
+    pub use crate::barnea::Barnea;
+    pub use crate::beggs_brill::BeggsBrill;
+    pub use crate::blend::Blended;
+    pub use crate::dispatch::{Dispatch, Orientation};
+    pub use crate::duns_ros::DunsRos;
+    pub use crate::error::TwoPhaseError;
+    pub use crate::fittings::{
+        equivalent_length, fittings_loss, Fitting, FittingModel, FittingResult,
+    };
+    pub use crate::friction::FrictionFactor;
+    pub use crate::frictional::{Frictional, FrictionalResult};
+    pub use crate::hagedorn_brown::HagedornBrown;
+    pub use crate::homogeneous::{Fallback, Homogeneous, MixtureViscosity};
+    pub use crate::horizontal::Horizontal;
+    pub use crate::input::{FluidStreams, InputError, LineInput, PipeGeometry};
+    pub use crate::pipeline::{GasLaw, Pipeline, Profile, Segment};
+    pub use crate::result::{Blend, Failure, LineResult, RegimeDetail};
+    pub use crate::schedule::{Material, PipeSize};
+    pub use crate::sizing::{size_line, Rejection, SizeCase, Sizing, SizingCriteria};
+    pub use crate::survey::Survey;
+    pub use crate::twoline::{FlowPattern, Regime, RegimeReport, Transition, TwoPhaseLine};
+    pub use crate::units::{Quantity, UnitSystem};
+    pub use crate::vertical_down::VerticalDown;
+    pub use crate::vertical_up::VerticalUp;
+}
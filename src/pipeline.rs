@@ -0,0 +1,308 @@
+#![allow(non_snake_case)]
+
+// Pipeline made of segments, marched from inlet to outlet.
+//
+// Every model evaluates one point at a fixed vapor density and reports gradients per
+// 100 m. A Pipeline splits each segment into steps no longer than `step`, updates the
+// vapor density from the local pressure and re-evaluates the regime and gradient at
+// each step. A step is evaluated at the inlet pressure to estimate its mid-step
+// pressure, then again at the mid-step pressure for the pressure change.
+//
+// Each step takes the model's frictional, elevation and acceleration gradients. The
+// elevation gradient, Pgrav, follows from each step's own holdup, so a downhill leg,
+// usually running at a lower holdup, recovers less head than the uphill leg lost;
+// `recovery` scales the downhill gain further (0 ignores it, the conservative design
+// practice).
+//
+// The fittings of a segment are counted as equivalent length (Crane L/D x ID) on the
+// frictional gradient only.
+
+use crate::dispatch::Dispatch;
+use crate::error::TwoPhaseError;
+use crate::fittings::{equivalent_length, Fitting};
+use crate::input::{finite, in_range, positive, InputError, LineInput, PipeGeometry};
+use crate::result::LineResult;
+use crate::twoline::TwoPhaseLine;
+
+pub const DEFAULT_STEP: f64 = 100.0; // [m]
+
+// Vapor density as a function of pressure, from the density given at the inlet
+#[derive(Debug, Clone, Copy, Default)]
+pub enum GasLaw {
+    // vapor density fixed at the inlet value
+    #[default]
+    Constant,
+    // isothermal ideal gas, LoG proportional to P
+    Ideal,
+    // isothermal real gas, LoG proportional to P / Z(P), P [kgf/cm^2 abs]
+    Real(fn(f64) -> f64),
+}
+
+impl GasLaw {
+    // vapor density [kg/m^3] at P, from LoG0 at P0 [kgf/cm^2 abs]
+    pub fn density(self, LoG0: f64, P0: f64, P: f64) -> f64 {
+        match self {
+            GasLaw::Constant => LoG0,
+            GasLaw::Ideal => LoG0 * P / P0,
+            GasLaw::Real(Z) => LoG0 * P / P0 * Z(P0) / Z(P),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub length: f64,                     // length along the pipe [m]
+    pub pipe: PipeGeometry,              // ID, roughness and inclination of the segment
+    pub fittings: Vec<(Fitting, usize)>, // fittings inventory, (fitting, count)
+}
+
+impl Segment {
+    pub fn new(length: f64, pipe: PipeGeometry) -> Self {
+        Segment {
+            length,
+            pipe,
+            fittings: Vec::new(),
+        }
+    }
+
+    // segment rising `rise` [m] over its length, the inclination of `pipe` is replaced
+    pub fn with_rise(length: f64, mut pipe: PipeGeometry, rise: f64) -> Result<Self, InputError> {
+        positive("length", length)?;
+        finite("rise", rise)?;
+        in_range("rise", rise, -length, length)?;
+        pipe.degree = -(rise / length).asin().to_degrees(); // -Up / +Down
+        Ok(Segment::new(length, pipe))
+    }
+
+    pub fn fittings(mut self, fittings: &[(Fitting, usize)]) -> Self {
+        self.fittings = fittings.to_vec();
+        self
+    }
+
+    // equivalent length of the fittings [m]
+    pub fn fittings_length(&self) -> f64 {
+        equivalent_length(&self.fittings, self.pipe.ID)
+    }
+
+    // pressure drop over the segment from one gradient evaluation [kgf/cm^2]: Pfric on
+    // the length plus the fittings' equivalent length, Pgrav and Pacc on the length
+    pub fn dP(&self, r: &LineResult) -> f64 {
+        (r.Pfric * (self.length + self.fittings_length()) + (r.Pgrav + r.Pacc) * self.length)
+            / 100.0
+    }
+
+    // elevation change from inlet to outlet [m], positive uphill
+    pub fn rise(&self) -> f64 {
+        self.length * self.pipe.rise_per_length()
+    }
+}
+
+// State at the end of one step
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfilePoint {
+    pub segment: usize,     // index of the segment the step belongs to
+    pub distance: f64,      // distance from the inlet [m]
+    pub elevation: f64,     // elevation relative to the inlet [m]
+    pub pressure: f64,      // pressure [kgf/cm^2 abs]
+    pub length: f64,        // step length [m]
+    pub LoG: f64,           // vapor density at mid-step [kg/m^3]
+    pub Pfric: f64,         // frictional gradient, fittings included [kgf/cm^2/100m]
+    pub Pelev: f64,         // elevation gradient after recovery, negative downhill [kgf/cm^2/100m]
+    pub Pacc: f64,          // acceleration gradient [kgf/cm^2/100m]
+    pub gradient: f64,      // total gradient, Pfric + Pelev + Pacc [kgf/cm^2/100m]
+    pub result: LineResult, // model result at mid-step, Pfric and Pgrav as above
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub inlet_pressure: f64,       // [kgf/cm^2 abs]
+    pub points: Vec<ProfilePoint>, // one per step, inlet to outlet
+}
+
+impl Profile {
+    pub fn outlet_pressure(&self) -> f64 {
+        self.points
+            .last()
+            .map_or(self.inlet_pressure, |p| p.pressure)
+    }
+
+    // total pressure drop, inlet - outlet [kgf/cm^2]
+    pub fn dP(&self) -> f64 {
+        self.inlet_pressure - self.outlet_pressure()
+    }
+
+    fn sum(&self, term: impl Fn(&ProfilePoint) -> f64) -> f64 {
+        self.points.iter().map(|p| term(p) * p.length / 100.0).sum()
+    }
+
+    // frictional loss [kgf/cm^2]
+    pub fn friction_loss(&self) -> f64 {
+        self.sum(|p| p.Pfric)
+    }
+
+    // acceleration loss [kgf/cm^2]
+    pub fn acceleration_loss(&self) -> f64 {
+        self.sum(|p| p.Pacc)
+    }
+
+    // elevation loss on the uphill legs [kgf/cm^2]
+    pub fn uphill_loss(&self) -> f64 {
+        self.sum(|p| p.Pelev.max(0.0))
+    }
+
+    // elevation gain recovered on the downhill legs [kgf/cm^2];
+    // dP = friction_loss + acceleration_loss + uphill_loss - downhill_recovery
+    pub fn downhill_recovery(&self) -> f64 {
+        self.sum(|p| (-p.Pelev).max(0.0))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    pub segments: Vec<Segment>,
+    pub step: f64,     // maximum step length [m]
+    pub gas: GasLaw,   // vapor density update along the line
+    pub recovery: f64, // fraction of the downhill elevation gain recovered [-]
+}
+
+impl Pipeline {
+    pub fn new(segments: Vec<Segment>) -> Self {
+        Pipeline {
+            segments,
+            step: DEFAULT_STEP,
+            gas: GasLaw::default(),
+            recovery: 1.0,
+        }
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn gas_law(mut self, gas: GasLaw) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    pub fn recovery(mut self, recovery: f64) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    // Marches with the orientation model selected for each segment's inclination.
+    // `input` gives the flows and fluid properties, its vapor density at the inlet
+    // pressure P [kgf/cm^2 abs]; its pipe is replaced by each segment's.
+    pub fn march(&self, input: &LineInput, P: f64) -> Result<Profile, TwoPhaseError> {
+        self.march_by(input, P, |pipe| Box::new(Dispatch::new(pipe.degree)))
+    }
+
+    // Marches with the same model on every segment. A model holding a line pressure,
+    // such as HagedornBrown, keeps that pressure along the whole line.
+    pub fn march_with(
+        &self,
+        line: &dyn TwoPhaseLine,
+        input: &LineInput,
+        P: f64,
+    ) -> Result<Profile, TwoPhaseError> {
+        self.march_by(input, P, |_| Box::new(line))
+    }
+
+    fn march_by<'a>(
+        &self,
+        input: &LineInput,
+        P: f64,
+        line_for: impl Fn(&PipeGeometry) -> Box<dyn TwoPhaseLine + 'a>,
+    ) -> Result<Profile, TwoPhaseError> {
+        positive("step", self.step)?;
+        finite("recovery", self.recovery)?;
+        in_range("recovery", self.recovery, 0.0, 1.0)?;
+        TwoPhaseError::check_range("inlet pressure", P, f64::MIN_POSITIVE, f64::INFINITY)?;
+        let LoG0 = input.fluid.LoG;
+        let mut points = Vec::new();
+        let (mut pressure, mut distance, mut elevation) = (P, 0.0, 0.0);
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            positive("length", segment.length)?;
+            let line = line_for(&segment.pipe);
+            let n = (segment.length / self.step).ceil().max(1.0) as usize;
+            let dx = segment.length / n as f64;
+            let sin = segment.pipe.rise_per_length();
+            let recovery = if sin < 0.0 { self.recovery } else { 1.0 };
+            let stretch = 1.0 + segment.fittings_length() / segment.length; // fittings [-]
+
+            // model result at pressure Pk and its total gradient [kgf/cm^2/100m]
+            let evaluate = |Pk: f64| -> Result<(f64, LineResult), TwoPhaseError> {
+                TwoPhaseError::check_range("pressure", Pk, f64::MIN_POSITIVE, f64::INFINITY)?;
+                let mut trial = input.clone();
+                trial.pipe = segment.pipe.clone();
+                trial.fluid.LoG = self.gas.density(LoG0, P, Pk);
+                trial.validate()?;
+                let mut r = line.gradient(&trial)?;
+                r.Pfric *= stretch;
+                r.Pgrav -= r.Pgrav * (1.0 - recovery); // the part not recovered downhill
+                Ok((r.total_gradient(), r))
+            };
+
+            for _ in 0..n {
+                let (inlet, _) = evaluate(pressure)?;
+                let Pmid = pressure - inlet * dx / 100.0 / 2.0;
+                let (gradient, result) = evaluate(Pmid)?;
+                pressure -= gradient * dx / 100.0;
+                distance += dx;
+                elevation += dx * sin;
+                TwoPhaseError::check_range("pressure", pressure, f64::MIN_POSITIVE, f64::INFINITY)?;
+                points.push(ProfilePoint {
+                    segment: index,
+                    distance,
+                    elevation,
+                    pressure,
+                    length: dx,
+                    LoG: self.gas.density(LoG0, P, Pmid),
+                    Pfric: result.Pfric,
+                    Pelev: result.Pgrav,
+                    Pacc: result.Pacc,
+                    gradient,
+                    result,
+                });
+            }
+        }
+        Ok(Profile {
+            inlet_pressure: P,
+            points,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::twoline::Regime;
+
+    #[test]
+    fn segment_fittings_stretch_the_frictional_length_only() {
+        let pipe = PipeGeometry::from_nps("6", "40").unwrap();
+        let segment = Segment::with_rise(200.0, pipe, 20.0)
+            .unwrap()
+            .fittings(&[(Fitting::Elbow90LongRadius, 5), (Fitting::GateValve, 2)]);
+        let fittings_length = 86.0 * 0.154051; // (5 x 14 + 2 x 8) D [m]
+        assert!((segment.fittings_length() - fittings_length).abs() < 1e-9);
+
+        let r = LineResult {
+            regime: Regime::NONE,
+            holdup: 0.2,
+            density: 200.0,
+            velocity: 5.0,
+            Pfric: 0.5,
+            Pgrav: 0.03,
+            Pacc: 0.01,
+            Head: 0.0,
+            Ef: 0.0,
+            detail: None,
+            blend: None,
+            fallback: None,
+        };
+        let dP = (0.5 * (200.0 + fittings_length) + 0.04 * 200.0) / 100.0;
+        assert!((segment.dP(&r) - dP).abs() < 1e-12);
+    }
+}
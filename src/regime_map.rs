@@ -0,0 +1,366 @@
+#![allow(non_snake_case)]
+
+// Flow regime map on the superficial velocity plane.
+//
+// For the fluid properties and geometry of one LineInput, the regime of any
+// TwoPhaseLine is evaluated over a log-spaced UGS x ULS grid [m/s]. Every pair of
+// neighbouring grid points with different regimes is refined by bisection (in log
+// space) to a point on the transition boundary between them. The map can be written
+// as CSV or as a standalone SVG plot with the operating point marked.
+
+use std::fmt::Write;
+
+use crate::input::{positive, InputError, LineInput};
+use crate::twoline::{FlowPattern, Regime, TwoPhaseLine};
+
+const REFINE: usize = 16; // bisection steps per boundary point
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapOptions {
+    pub UGS: (f64, f64), // Vapor Superficial Velocity axis range [m/s]
+    pub ULS: (f64, f64), // Liquid Superficial Velocity axis range [m/s]
+    pub points: usize,   // grid points per axis
+}
+
+impl Default for MapOptions {
+    fn default() -> Self {
+        MapOptions {
+            UGS: (0.01, 100.0),
+            ULS: (0.001, 10.0),
+            points: 60,
+        }
+    }
+}
+
+impl MapOptions {
+    // axis ranges finite, positive and increasing, at least two points per axis
+    pub fn validate(&self) -> Result<(), InputError> {
+        let axis = |(lo, hi): (f64, f64), min: &'static str, max: &'static str| {
+            positive(min, lo)?;
+            positive(max, hi)?;
+            if hi <= lo {
+                return Err(InputError::OutOfRange {
+                    field: max,
+                    value: hi,
+                    min: lo,
+                    max: f64::INFINITY,
+                });
+            }
+            Ok(())
+        };
+        axis(self.UGS, "UGS min", "UGS max")?;
+        axis(self.ULS, "ULS min", "ULS max")?;
+        if self.points < 2 {
+            return Err(InputError::OutOfRange {
+                field: "points",
+                value: self.points as f64,
+                min: 2.0,
+                max: f64::INFINITY,
+            });
+        }
+        Ok(())
+    }
+}
+
+// Points where the regime changes from `from` to `to` (UGS, ULS) [m/s]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Boundary {
+    pub from: Regime,
+    pub to: Regime,
+    pub points: Vec<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegimeMap {
+    pub method: &'static str,
+    pub UGS: Vec<f64>,                   // grid Vapor Superficial Velocity [m/s]
+    pub ULS: Vec<f64>,                   // grid Liquid Superficial Velocity [m/s]
+    pub cells: Vec<Vec<Option<Regime>>>, // cells[i][j] at (UGS[j], ULS[i]), None if the map failed
+    pub boundaries: Vec<Boundary>,
+    pub operating: (f64, f64), // operating point (UGS, ULS) [m/s]
+    pub operating_regime: Option<Regime>,
+}
+
+fn log_space((lo, hi): (f64, f64), n: usize) -> Vec<f64> {
+    let (a, b) = (lo.ln(), hi.ln());
+    (0..n)
+        .map(|k| (a + (b - a) * k as f64 / (n - 1) as f64).exp())
+        .collect()
+}
+
+pub fn regime_map(
+    line: &dyn TwoPhaseLine,
+    input: &LineInput,
+    options: MapOptions,
+) -> Result<RegimeMap, InputError> {
+    options.validate()?;
+    let regime_at = |UGS: f64, ULS: f64| {
+        line.regime(&input.with_superficial_velocities(UGS, ULS))
+            .ok()
+            .map(|r| r.regime)
+    };
+    let UGS = log_space(options.UGS, options.points);
+    let ULS = log_space(options.ULS, options.points);
+    let cells: Vec<Vec<Option<Regime>>> = ULS
+        .iter()
+        .map(|&y| UGS.iter().map(|&x| regime_at(x, y)).collect())
+        .collect();
+
+    // refine the change between two grid points; `along_x` moves UGS, otherwise ULS
+    let refine = |fixed: f64, lo: f64, hi: f64, low: &Option<Regime>, along_x: bool| {
+        let at = |v: f64| {
+            if along_x {
+                regime_at(v, fixed)
+            } else {
+                regime_at(fixed, v)
+            }
+        };
+        let (mut a, mut b) = (lo.ln(), hi.ln());
+        for _ in 0..REFINE {
+            let m = (a + b) / 2.0;
+            if at(m.exp()) == *low {
+                a = m;
+            } else {
+                b = m;
+            }
+        }
+        ((a + b) / 2.0).exp()
+    };
+
+    let mut boundaries: Vec<Boundary> = Vec::new();
+    let mut add = |from: &Regime, to: &Regime, point: (f64, f64)| match boundaries
+        .iter_mut()
+        .find(|b| (b.from == *from && b.to == *to) || (b.from == *to && b.to == *from))
+    {
+        Some(b) => b.points.push(point),
+        None => boundaries.push(Boundary {
+            from: from.clone(),
+            to: to.clone(),
+            points: vec![point],
+        }),
+    };
+    for (i, row) in cells.iter().enumerate() {
+        for (j, cell) in row.iter().enumerate() {
+            if let (Some(r), Some(Some(right))) = (cell, row.get(j + 1)) {
+                if r != right {
+                    let x = refine(ULS[i], UGS[j], UGS[j + 1], cell, true);
+                    add(r, right, (x, ULS[i]));
+                }
+            }
+            if let (Some(r), Some(Some(up))) = (cell, cells.get(i + 1).map(|row| &row[j])) {
+                if r != up {
+                    let y = refine(UGS[j], ULS[i], ULS[i + 1], cell, false);
+                    add(r, up, (UGS[j], y));
+                }
+            }
+        }
+    }
+
+    let operating = input.superficial_velocities();
+    Ok(RegimeMap {
+        method: line.method(),
+        UGS,
+        ULS,
+        cells,
+        boundaries,
+        operating,
+        operating_regime: regime_at(operating.0, operating.1),
+    })
+}
+
+// fill colour of a regime area
+fn colour(regime: Option<&Regime>) -> &'static str {
+    match regime.and_then(|r| r.pattern()) {
+        Some(FlowPattern::StratifiedSmooth) => "#cfe8ff",
+        Some(FlowPattern::StratifiedWavy) => "#9fcfff",
+        Some(FlowPattern::Annular) => "#ffe39f",
+        Some(FlowPattern::Intermittent) => "#ffb8b8",
+        Some(FlowPattern::Bubble) => "#d2f2d2",
+        Some(FlowPattern::DispersedBubble) => "#92dc92",
+        None => "#dddddd",
+    }
+}
+
+fn name(regime: Option<&Regime>) -> &str {
+    regime.map_or("-", |r| r.name())
+}
+
+impl RegimeMap {
+    // one row per grid point, boundary point and the operating point:
+    // kind,UGS [m/s],ULS [m/s],regime,neighbour
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("kind,UGS [m/s],ULS [m/s],regime,neighbour\n");
+        for (i, row) in self.cells.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "grid,{:.6e},{:.6e},{},",
+                    self.UGS[j],
+                    self.ULS[i],
+                    name(cell.as_ref())
+                );
+            }
+        }
+        for b in &self.boundaries {
+            for (x, y) in &b.points {
+                let _ = writeln!(out, "boundary,{:.6e},{:.6e},{},{}", x, y, b.from, b.to);
+            }
+        }
+        let _ = writeln!(
+            out,
+            "operating,{:.6e},{:.6e},{},",
+            self.operating.0,
+            self.operating.1,
+            name(self.operating_regime.as_ref())
+        );
+        out
+    }
+
+    pub fn to_svg(&self) -> String {
+        const W: f64 = 800.0; // plot width [px]
+        const H: f64 = 560.0; // plot height [px]
+        const LEFT: f64 = 70.0;
+        const TOP: f64 = 40.0;
+        const LEGEND: f64 = 260.0;
+        let (x0, x1) = (self.UGS[0].ln(), self.UGS[self.UGS.len() - 1].ln());
+        let (y0, y1) = (self.ULS[0].ln(), self.ULS[self.ULS.len() - 1].ln());
+        let px = |x: f64| LEFT + (x.ln() - x0) / (x1 - x0) * W;
+        let py = |y: f64| TOP + H - (y.ln() - y0) / (y1 - y0) * H;
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="sans-serif" font-size="12">"#,
+            LEFT + W + LEGEND,
+            TOP + H + 60.0
+        );
+        let _ = writeln!(
+            out,
+            r#"<text x="{}" y="20" font-size="14">Flow regime map - {}</text>"#,
+            LEFT, self.method
+        );
+
+        // regime areas, one rectangle per grid point spanning half way to its neighbours
+        let edge = |v: &[f64], k: usize| -> (f64, f64) {
+            let lo = if k == 0 {
+                v[0]
+            } else {
+                (v[k - 1] * v[k]).sqrt()
+            };
+            let hi = if k + 1 == v.len() {
+                v[k]
+            } else {
+                (v[k] * v[k + 1]).sqrt()
+            };
+            (lo, hi)
+        };
+        for (i, row) in self.cells.iter().enumerate() {
+            let (ylo, yhi) = edge(&self.ULS, i);
+            for (j, cell) in row.iter().enumerate() {
+                let (xlo, xhi) = edge(&self.UGS, j);
+                let _ = writeln!(
+                    out,
+                    r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="{}"/>"#,
+                    px(xlo),
+                    py(yhi),
+                    px(xhi) - px(xlo),
+                    py(ylo) - py(yhi),
+                    colour(cell.as_ref())
+                );
+            }
+        }
+
+        // transition boundaries
+        for b in &self.boundaries {
+            for (x, y) in &b.points {
+                let _ = writeln!(
+                    out,
+                    r#"<circle cx="{:.2}" cy="{:.2}" r="1.6" fill="black"/>"#,
+                    px(*x),
+                    py(*y)
+                );
+            }
+        }
+
+        // log axes with decade ticks
+        let _ = writeln!(
+            out,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="black"/>"#,
+            LEFT, TOP, W, H
+        );
+        let decades = |lo: f64, hi: f64| {
+            (lo.log10().ceil() as i32..=hi.log10().floor() as i32).map(|e| 10f64.powi(e))
+        };
+        for x in decades(self.UGS[0], self.UGS[self.UGS.len() - 1]) {
+            let _ = writeln!(
+                out,
+                r#"<line x1="{0:.2}" y1="{1}" x2="{0:.2}" y2="{2}" stroke="grey" stroke-dasharray="2,3"/><text x="{0:.2}" y="{3}" text-anchor="middle">{4}</text>"#,
+                px(x),
+                TOP,
+                TOP + H,
+                TOP + H + 16.0,
+                x
+            );
+        }
+        for y in decades(self.ULS[0], self.ULS[self.ULS.len() - 1]) {
+            let _ = writeln!(
+                out,
+                r#"<line x1="{1}" y1="{0:.2}" x2="{2}" y2="{0:.2}" stroke="grey" stroke-dasharray="2,3"/><text x="{3}" y="{0:.2}" text-anchor="end" dominant-baseline="middle">{4}</text>"#,
+                py(y),
+                LEFT,
+                LEFT + W,
+                LEFT - 6.0,
+                y
+            );
+        }
+        let _ = writeln!(
+            out,
+            r#"<text x="{}" y="{}" text-anchor="middle">UGS [m/s]</text>"#,
+            LEFT + W / 2.0,
+            TOP + H + 40.0
+        );
+        let _ = writeln!(
+            out,
+            r#"<text x="18" y="{0}" text-anchor="middle" transform="rotate(-90 18 {0})">ULS [m/s]</text>"#,
+            TOP + H / 2.0
+        );
+
+        // operating point
+        let (ox, oy) = (px(self.operating.0), py(self.operating.1));
+        let _ = writeln!(
+            out,
+            r#"<circle cx="{:.2}" cy="{:.2}" r="6" fill="red" stroke="black"/>"#,
+            ox, oy
+        );
+        let _ = writeln!(
+            out,
+            r#"<text x="{:.2}" y="{:.2}">{}</text>"#,
+            ox + 9.0,
+            oy - 9.0,
+            name(self.operating_regime.as_ref())
+        );
+
+        // legend of the regimes present on the map
+        let mut seen: Vec<Option<&Regime>> = Vec::new();
+        for cell in self.cells.iter().flatten() {
+            if !seen.contains(&cell.as_ref()) {
+                seen.push(cell.as_ref());
+            }
+        }
+        for (k, r) in seen.iter().enumerate() {
+            let y = TOP + 10.0 + 22.0 * k as f64;
+            let _ = writeln!(
+                out,
+                r#"<rect x="{0}" y="{1}" width="14" height="14" fill="{2}" stroke="black"/><text x="{3}" y="{4}">{5}</text>"#,
+                LEFT + W + 16.0,
+                y,
+                colour(*r),
+                LEFT + W + 36.0,
+                y + 11.0,
+                name(*r)
+            );
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
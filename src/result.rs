@@ -0,0 +1,82 @@
+#![allow(non_snake_case)]
+
+use crate::error::TwoPhaseError;
+use crate::twoline::Regime;
+
+// Common output of every model. The core fields are always populated whatever the
+// regime; regime-specific values are carried in `detail`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineResult {
+    pub regime: Regime, // identified flow regime
+    pub holdup: f64,    // Liquid Volume Fraction (in-situ) [-]
+    pub density: f64,   // Two-Phase (mixture) Density, holdup weighted [kg/m^3]
+    pub velocity: f64,  // Two-Phase (mixture) Velocity, ULS + UGS [m/s]
+    pub Pfric: f64,     // Frictional Pressure Loss [kgf/cm^2/100m]
+    pub Pgrav: f64,     // Elevation Head Loss [kgf/cm^2/100m], negative downhill
+    pub Pacc: f64,      // Acceleration Loss [kgf/cm^2/100m], not included in Pfric
+    pub Head: f64,      // 1.0 Velocity Head [kgf/cm^2]
+    pub Ef: f64,        // Erosion Factor [-], Φ ≤ 1 : No Erosion, Φ > 1, Erosion occurred
+    pub detail: Option<RegimeDetail>,
+    pub blend: Option<Blend>, // set when the result was blended across a regime transition
+    pub fallback: Option<Failure>, // set when the result replaces a model that failed
+}
+
+// Blending applied near a regime transition: every core field is
+// (1 - weight) * model of `regime` + weight * model of `neighbour`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blend {
+    pub neighbour: Regime, // regime across the nearest transition
+    pub weight: f64,       // weight of the neighbour's model, 0 to 0.5 [-]
+}
+
+// The model that failed and why; the result holding it comes from the
+// homogeneous equilibrium model instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub method: &'static str, // method of the failed model
+    pub error: TwoPhaseError,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegimeDetail {
+    Slug {
+        LoLS: f64,       // Liquid Slug Density [kg/m^3]
+        LoSU: f64,       // Two-Phase Slug Unit Density [kg/m^3]
+        Us: f64,         // Liquid Slug Velocity [m/s]
+        Ls: Option<f64>, // Liquid Slug Length [m]
+        Lu: Option<f64>, // Slug Unit Length (Liq + Vap) [m]
+        Le: Option<f64>, // Stabilizes to Slug Flow in x m [m]
+    },
+    Stratified {
+        depth: f64, // Liquid Depth - BOP [m]
+        velL: f64,  // Liquid Velocity [m/s]
+        velG: f64,  // Vapor Velocity [m/s]
+    },
+    PhaseVelocities {
+        velL: f64, // Liquid Velocity, ULS / holdup [m/s]
+        velG: f64, // Vapor Velocity, UGS / (1 - holdup) [m/s]
+    },
+}
+
+impl RegimeDetail {
+    pub fn phase_velocities(ULS: f64, UGS: f64, holdup: f64) -> Self {
+        RegimeDetail::PhaseVelocities {
+            velL: ULS / holdup,
+            velG: UGS / (1.0 - holdup),
+        }
+    }
+}
+
+impl LineResult {
+    // Pfric + Pgrav + Pacc [kgf/cm^2/100m]
+    pub fn total_gradient(&self) -> f64 {
+        self.Pfric + self.Pgrav + self.Pacc
+    }
+
+    // reject a result that a correlation has pushed outside its physical range
+    pub(crate) fn checked(self) -> Result<Self, TwoPhaseError> {
+        TwoPhaseError::check_range("holdup", self.holdup, 0.0, 1.0)?;
+        TwoPhaseError::check_range("Pfric", self.Pfric, 0.0, f64::MAX)?;
+        Ok(self)
+    }
+}
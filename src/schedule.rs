@@ -0,0 +1,797 @@
+#![allow(non_snake_case)]
+
+// Standard pipe sizes of ASME B36.10M (welded and seamless wrought steel pipe) and
+// ASME B36.19M (stainless steel pipe, the "S" schedules), NPS 1/8" through 48", and
+// the default absolute roughness of the common pipe materials.
+//
+// Sizes are looked up by nominal pipe size and schedule strings as written on a line
+// list: NPS as "6", "1-1/2", "1 1/2" or "1.5" (an inch mark is ignored) and schedule
+// as "40", "SCH 40", "Sch40", "STD", "XS", "XXS" or "10S" (case is ignored).
+
+use crate::input::{InputError, DEFAULT_ROUGHNESS};
+use crate::units::INCH;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Material {
+    // commercial carbon steel
+    #[default]
+    CarbonSteel,
+    // drawn stainless steel
+    StainlessSteel,
+    // glass reinforced epoxy
+    GRE,
+    // plastic (PTFE, PP, PE) lined steel
+    Lined,
+}
+
+impl Material {
+    pub fn name(self) -> &'static str {
+        match self {
+            Material::CarbonSteel => "carbon steel",
+            Material::StainlessSteel => "stainless steel",
+            Material::GRE => "GRE",
+            Material::Lined => "lined pipe",
+        }
+    }
+
+    // default absolute roughness [m]
+    pub fn roughness(self) -> f64 {
+        match self {
+            Material::CarbonSteel => DEFAULT_ROUGHNESS,
+            Material::StainlessSteel => 1.5e-5,
+            Material::GRE => 5.3e-6,
+            Material::Lined => 1.5e-6,
+        }
+    }
+}
+
+// One size and schedule of the table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PipeSize {
+    pub nps: &'static str,      // nominal pipe size [inch]
+    pub schedule: &'static str, // schedule, as tabulated
+    pub OD: f64,                // outside diameter [m]
+    pub WT: f64,                // wall thickness [m]
+}
+
+impl PipeSize {
+    // inside diameter [m]
+    pub fn ID(&self) -> f64 {
+        self.OD - 2.0 * self.WT
+    }
+}
+
+struct Nps {
+    nps: &'static str,
+    nominal: f64,                          // [inch]
+    OD: f64,                               // [inch]
+    walls: &'static [(&'static str, f64)], // (schedule, wall thickness [inch])
+}
+
+// ASME B36.10M / B36.19M, dimensions in inch (0.318 is a wall thickness, not 1/pi)
+#[allow(clippy::approx_constant)]
+const TABLE: &[Nps] = &[
+    Nps {
+        nps: "1/8",
+        nominal: 0.125,
+        OD: 0.405,
+        walls: &[
+            ("10", 0.049),
+            ("10S", 0.049),
+            ("30", 0.057),
+            ("40", 0.068),
+            ("STD", 0.068),
+            ("40S", 0.068),
+            ("80", 0.095),
+            ("XS", 0.095),
+            ("80S", 0.095),
+        ],
+    },
+    Nps {
+        nps: "1/4",
+        nominal: 0.25,
+        OD: 0.540,
+        walls: &[
+            ("10", 0.065),
+            ("10S", 0.065),
+            ("30", 0.073),
+            ("40", 0.088),
+            ("STD", 0.088),
+            ("40S", 0.088),
+            ("80", 0.119),
+            ("XS", 0.119),
+            ("80S", 0.119),
+        ],
+    },
+    Nps {
+        nps: "3/8",
+        nominal: 0.375,
+        OD: 0.675,
+        walls: &[
+            ("10", 0.065),
+            ("10S", 0.065),
+            ("30", 0.073),
+            ("40", 0.091),
+            ("STD", 0.091),
+            ("40S", 0.091),
+            ("80", 0.126),
+            ("XS", 0.126),
+            ("80S", 0.126),
+        ],
+    },
+    Nps {
+        nps: "1/2",
+        nominal: 0.5,
+        OD: 0.840,
+        walls: &[
+            ("5", 0.065),
+            ("5S", 0.065),
+            ("10", 0.083),
+            ("10S", 0.083),
+            ("30", 0.095),
+            ("40", 0.109),
+            ("STD", 0.109),
+            ("40S", 0.109),
+            ("80", 0.147),
+            ("XS", 0.147),
+            ("80S", 0.147),
+            ("160", 0.188),
+            ("XXS", 0.294),
+        ],
+    },
+    Nps {
+        nps: "3/4",
+        nominal: 0.75,
+        OD: 1.050,
+        walls: &[
+            ("5", 0.065),
+            ("5S", 0.065),
+            ("10", 0.083),
+            ("10S", 0.083),
+            ("30", 0.095),
+            ("40", 0.113),
+            ("STD", 0.113),
+            ("40S", 0.113),
+            ("80", 0.154),
+            ("XS", 0.154),
+            ("80S", 0.154),
+            ("160", 0.219),
+            ("XXS", 0.308),
+        ],
+    },
+    Nps {
+        nps: "1",
+        nominal: 1.0,
+        OD: 1.315,
+        walls: &[
+            ("5", 0.065),
+            ("5S", 0.065),
+            ("10", 0.109),
+            ("10S", 0.109),
+            ("30", 0.114),
+            ("40", 0.133),
+            ("STD", 0.133),
+            ("40S", 0.133),
+            ("80", 0.179),
+            ("XS", 0.179),
+            ("80S", 0.179),
+            ("160", 0.250),
+            ("XXS", 0.358),
+        ],
+    },
+    Nps {
+        nps: "1-1/4",
+        nominal: 1.25,
+        OD: 1.660,
+        walls: &[
+            ("5", 0.065),
+            ("5S", 0.065),
+            ("10", 0.109),
+            ("10S", 0.109),
+            ("30", 0.117),
+            ("40", 0.140),
+            ("STD", 0.140),
+            ("40S", 0.140),
+            ("80", 0.191),
+            ("XS", 0.191),
+            ("80S", 0.191),
+            ("160", 0.250),
+            ("XXS", 0.382),
+        ],
+    },
+    Nps {
+        nps: "1-1/2",
+        nominal: 1.5,
+        OD: 1.900,
+        walls: &[
+            ("5", 0.065),
+            ("5S", 0.065),
+            ("10", 0.109),
+            ("10S", 0.109),
+            ("30", 0.125),
+            ("40", 0.145),
+            ("STD", 0.145),
+            ("40S", 0.145),
+            ("80", 0.200),
+            ("XS", 0.200),
+            ("80S", 0.200),
+            ("160", 0.281),
+            ("XXS", 0.400),
+        ],
+    },
+    Nps {
+        nps: "2",
+        nominal: 2.0,
+        OD: 2.375,
+        walls: &[
+            ("5", 0.065),
+            ("5S", 0.065),
+            ("10", 0.109),
+            ("10S", 0.109),
+            ("30", 0.125),
+            ("40", 0.154),
+            ("STD", 0.154),
+            ("40S", 0.154),
+            ("80", 0.218),
+            ("XS", 0.218),
+            ("80S", 0.218),
+            ("160", 0.344),
+            ("XXS", 0.436),
+        ],
+    },
+    Nps {
+        nps: "2-1/2",
+        nominal: 2.5,
+        OD: 2.875,
+        walls: &[
+            ("5", 0.083),
+            ("5S", 0.083),
+            ("10", 0.120),
+            ("10S", 0.120),
+            ("30", 0.188),
+            ("40", 0.203),
+            ("STD", 0.203),
+            ("40S", 0.203),
+            ("80", 0.276),
+            ("XS", 0.276),
+            ("80S", 0.276),
+            ("160", 0.375),
+            ("XXS", 0.552),
+        ],
+    },
+    Nps {
+        nps: "3",
+        nominal: 3.0,
+        OD: 3.500,
+        walls: &[
+            ("5", 0.083),
+            ("5S", 0.083),
+            ("10", 0.120),
+            ("10S", 0.120),
+            ("30", 0.188),
+            ("40", 0.216),
+            ("STD", 0.216),
+            ("40S", 0.216),
+            ("80", 0.300),
+            ("XS", 0.300),
+            ("80S", 0.300),
+            ("160", 0.438),
+            ("XXS", 0.600),
+        ],
+    },
+    Nps {
+        nps: "3-1/2",
+        nominal: 3.5,
+        OD: 4.000,
+        walls: &[
+            ("5", 0.083),
+            ("5S", 0.083),
+            ("10", 0.120),
+            ("10S", 0.120),
+            ("30", 0.188),
+            ("40", 0.226),
+            ("STD", 0.226),
+            ("40S", 0.226),
+            ("80", 0.318),
+            ("XS", 0.318),
+            ("80S", 0.318),
+            ("XXS", 0.636),
+        ],
+    },
+    Nps {
+        nps: "4",
+        nominal: 4.0,
+        OD: 4.500,
+        walls: &[
+            ("5", 0.083),
+            ("5S", 0.083),
+            ("10", 0.120),
+            ("10S", 0.120),
+            ("30", 0.188),
+            ("40", 0.237),
+            ("STD", 0.237),
+            ("40S", 0.237),
+            ("80", 0.337),
+            ("XS", 0.337),
+            ("80S", 0.337),
+            ("120", 0.438),
+            ("160", 0.531),
+            ("XXS", 0.674),
+        ],
+    },
+    Nps {
+        nps: "5",
+        nominal: 5.0,
+        OD: 5.563,
+        walls: &[
+            ("5", 0.109),
+            ("5S", 0.109),
+            ("10", 0.134),
+            ("10S", 0.134),
+            ("40", 0.258),
+            ("STD", 0.258),
+            ("40S", 0.258),
+            ("80", 0.375),
+            ("XS", 0.375),
+            ("80S", 0.375),
+            ("120", 0.500),
+            ("160", 0.625),
+            ("XXS", 0.750),
+        ],
+    },
+    Nps {
+        nps: "6",
+        nominal: 6.0,
+        OD: 6.625,
+        walls: &[
+            ("5", 0.109),
+            ("5S", 0.109),
+            ("10", 0.134),
+            ("10S", 0.134),
+            ("40", 0.280),
+            ("STD", 0.280),
+            ("40S", 0.280),
+            ("80", 0.432),
+            ("XS", 0.432),
+            ("80S", 0.432),
+            ("120", 0.562),
+            ("160", 0.719),
+            ("XXS", 0.864),
+        ],
+    },
+    Nps {
+        nps: "8",
+        nominal: 8.0,
+        OD: 8.625,
+        walls: &[
+            ("5", 0.109),
+            ("5S", 0.109),
+            ("10", 0.148),
+            ("10S", 0.148),
+            ("20", 0.250),
+            ("30", 0.277),
+            ("40", 0.322),
+            ("STD", 0.322),
+            ("40S", 0.322),
+            ("60", 0.406),
+            ("80", 0.500),
+            ("XS", 0.500),
+            ("80S", 0.500),
+            ("100", 0.594),
+            ("120", 0.719),
+            ("140", 0.812),
+            ("160", 0.906),
+            ("XXS", 0.875),
+        ],
+    },
+    Nps {
+        nps: "10",
+        nominal: 10.0,
+        OD: 10.750,
+        walls: &[
+            ("5", 0.134),
+            ("5S", 0.134),
+            ("10", 0.165),
+            ("10S", 0.165),
+            ("20", 0.250),
+            ("30", 0.307),
+            ("40", 0.365),
+            ("STD", 0.365),
+            ("40S", 0.365),
+            ("60", 0.500),
+            ("XS", 0.500),
+            ("80S", 0.500),
+            ("80", 0.594),
+            ("100", 0.719),
+            ("120", 0.844),
+            ("140", 1.000),
+            ("160", 1.125),
+            ("XXS", 1.000),
+        ],
+    },
+    Nps {
+        nps: "12",
+        nominal: 12.0,
+        OD: 12.750,
+        walls: &[
+            ("5", 0.156),
+            ("5S", 0.156),
+            ("10", 0.180),
+            ("10S", 0.180),
+            ("20", 0.250),
+            ("30", 0.330),
+            ("STD", 0.375),
+            ("40S", 0.375),
+            ("40", 0.406),
+            ("XS", 0.500),
+            ("80S", 0.500),
+            ("60", 0.562),
+            ("80", 0.688),
+            ("100", 0.844),
+            ("120", 1.000),
+            ("140", 1.125),
+            ("160", 1.312),
+            ("XXS", 1.000),
+        ],
+    },
+    Nps {
+        nps: "14",
+        nominal: 14.0,
+        OD: 14.000,
+        walls: &[
+            ("5S", 0.156),
+            ("10S", 0.188),
+            ("10", 0.250),
+            ("20", 0.312),
+            ("30", 0.375),
+            ("STD", 0.375),
+            ("40S", 0.375),
+            ("40", 0.438),
+            ("XS", 0.500),
+            ("80S", 0.500),
+            ("60", 0.594),
+            ("80", 0.750),
+            ("100", 0.938),
+            ("120", 1.094),
+            ("140", 1.250),
+            ("160", 1.406),
+        ],
+    },
+    Nps {
+        nps: "16",
+        nominal: 16.0,
+        OD: 16.000,
+        walls: &[
+            ("5S", 0.165),
+            ("10S", 0.188),
+            ("10", 0.250),
+            ("20", 0.312),
+            ("30", 0.375),
+            ("STD", 0.375),
+            ("40S", 0.375),
+            ("40", 0.500),
+            ("XS", 0.500),
+            ("80S", 0.500),
+            ("60", 0.656),
+            ("80", 0.844),
+            ("100", 1.031),
+            ("120", 1.219),
+            ("140", 1.438),
+            ("160", 1.594),
+        ],
+    },
+    Nps {
+        nps: "18",
+        nominal: 18.0,
+        OD: 18.000,
+        walls: &[
+            ("5S", 0.165),
+            ("10S", 0.188),
+            ("10", 0.250),
+            ("20", 0.312),
+            ("STD", 0.375),
+            ("40S", 0.375),
+            ("30", 0.438),
+            ("XS", 0.500),
+            ("80S", 0.500),
+            ("40", 0.562),
+            ("60", 0.750),
+            ("80", 0.938),
+            ("100", 1.156),
+            ("120", 1.375),
+            ("140", 1.562),
+            ("160", 1.781),
+        ],
+    },
+    Nps {
+        nps: "20",
+        nominal: 20.0,
+        OD: 20.000,
+        walls: &[
+            ("5S", 0.188),
+            ("10S", 0.218),
+            ("10", 0.250),
+            ("20", 0.375),
+            ("STD", 0.375),
+            ("40S", 0.375),
+            ("30", 0.500),
+            ("XS", 0.500),
+            ("80S", 0.500),
+            ("40", 0.594),
+            ("60", 0.812),
+            ("80", 1.031),
+            ("100", 1.281),
+            ("120", 1.500),
+            ("140", 1.750),
+            ("160", 1.969),
+        ],
+    },
+    Nps {
+        nps: "22",
+        nominal: 22.0,
+        OD: 22.000,
+        walls: &[
+            ("5S", 0.188),
+            ("10S", 0.218),
+            ("10", 0.250),
+            ("20", 0.375),
+            ("STD", 0.375),
+            ("40S", 0.375),
+            ("30", 0.500),
+            ("XS", 0.500),
+            ("80S", 0.500),
+            ("60", 0.875),
+            ("80", 1.125),
+            ("100", 1.375),
+            ("120", 1.625),
+            ("140", 1.875),
+            ("160", 2.125),
+        ],
+    },
+    Nps {
+        nps: "24",
+        nominal: 24.0,
+        OD: 24.000,
+        walls: &[
+            ("5S", 0.218),
+            ("10S", 0.250),
+            ("10", 0.250),
+            ("20", 0.375),
+            ("STD", 0.375),
+            ("40S", 0.375),
+            ("XS", 0.500),
+            ("80S", 0.500),
+            ("30", 0.562),
+            ("40", 0.688),
+            ("60", 0.969),
+            ("80", 1.219),
+            ("100", 1.531),
+            ("120", 1.812),
+            ("140", 2.062),
+            ("160", 2.344),
+        ],
+    },
+    Nps {
+        nps: "26",
+        nominal: 26.0,
+        OD: 26.000,
+        walls: &[("10", 0.312), ("STD", 0.375), ("20", 0.500), ("XS", 0.500)],
+    },
+    Nps {
+        nps: "28",
+        nominal: 28.0,
+        OD: 28.000,
+        walls: &[
+            ("10", 0.312),
+            ("STD", 0.375),
+            ("20", 0.500),
+            ("XS", 0.500),
+            ("30", 0.625),
+        ],
+    },
+    Nps {
+        nps: "30",
+        nominal: 30.0,
+        OD: 30.000,
+        walls: &[
+            ("5S", 0.250),
+            ("10S", 0.312),
+            ("10", 0.312),
+            ("STD", 0.375),
+            ("20", 0.500),
+            ("XS", 0.500),
+            ("30", 0.625),
+        ],
+    },
+    Nps {
+        nps: "32",
+        nominal: 32.0,
+        OD: 32.000,
+        walls: &[
+            ("10", 0.312),
+            ("STD", 0.375),
+            ("20", 0.500),
+            ("XS", 0.500),
+            ("30", 0.625),
+            ("40", 0.688),
+        ],
+    },
+    Nps {
+        nps: "34",
+        nominal: 34.0,
+        OD: 34.000,
+        walls: &[
+            ("10", 0.312),
+            ("STD", 0.375),
+            ("20", 0.500),
+            ("XS", 0.500),
+            ("30", 0.625),
+            ("40", 0.688),
+        ],
+    },
+    Nps {
+        nps: "36",
+        nominal: 36.0,
+        OD: 36.000,
+        walls: &[
+            ("10", 0.312),
+            ("STD", 0.375),
+            ("20", 0.500),
+            ("XS", 0.500),
+            ("30", 0.625),
+            ("40", 0.750),
+        ],
+    },
+    Nps {
+        nps: "42",
+        nominal: 42.0,
+        OD: 42.000,
+        walls: &[
+            ("STD", 0.375),
+            ("20", 0.500),
+            ("XS", 0.500),
+            ("30", 0.625),
+            ("40", 0.750),
+        ],
+    },
+    Nps {
+        nps: "48",
+        nominal: 48.0,
+        OD: 48.000,
+        walls: &[("STD", 0.375), ("XS", 0.500)],
+    },
+];
+
+// NPS "1-1/2", "1 1/2", "1.5" or "1-1/2\"" to inch
+fn nominal(nps: &str) -> Option<f64> {
+    let s = nps.trim().trim_end_matches('"').trim();
+    let fraction = |s: &str| match s.split_once('/') {
+        Some((n, d)) => Some(n.trim().parse::<f64>().ok()? / d.trim().parse::<f64>().ok()?),
+        None => s.trim().parse::<f64>().ok(),
+    };
+    match s.split_once(['-', ' ']) {
+        Some((whole, part)) => Some(whole.trim().parse::<f64>().ok()? + fraction(part)?),
+        None => fraction(s),
+    }
+}
+
+// schedule "SCH 40", "Sch40", "40" or "std" to the tabulated "40" or "STD"
+fn schedule_name(schedule: &str) -> String {
+    let s = schedule.trim().to_ascii_uppercase();
+    let s = s
+        .strip_prefix("SCHEDULE")
+        .or_else(|| s.strip_prefix("SCH"))
+        .unwrap_or(&s);
+    s.trim_start_matches(['.', ' ']).to_string()
+}
+
+fn size(row: &Nps, wall: &(&'static str, f64)) -> PipeSize {
+    PipeSize {
+        nps: row.nps,
+        schedule: wall.0,
+        OD: row.OD * INCH,
+        WT: wall.1 * INCH,
+    }
+}
+
+// Size of the given NPS and schedule, Err(UnknownPipeSize) when not tabulated
+pub fn lookup(nps: &str, schedule: &str) -> Result<PipeSize, InputError> {
+    let unknown = || InputError::UnknownPipeSize {
+        nps: nps.to_string(),
+        schedule: schedule.to_string(),
+    };
+    let n = nominal(nps).ok_or_else(unknown)?;
+    let sch = schedule_name(schedule);
+    let row = TABLE
+        .iter()
+        .find(|r| (r.nominal - n).abs() < 1e-6)
+        .ok_or_else(unknown)?;
+    row.walls
+        .iter()
+        .find(|w| w.0 == sch)
+        .map(|w| size(row, w))
+        .ok_or_else(unknown)
+}
+
+// Every tabulated size of the given schedule, smallest first
+pub fn sizes(schedule: &str) -> Vec<PipeSize> {
+    let sch = schedule_name(schedule);
+    TABLE
+        .iter()
+        .filter_map(|row| row.walls.iter().find(|w| w.0 == sch).map(|w| size(row, w)))
+        .collect()
+}
+
+// Every tabulated schedule of the given NPS, thinnest wall first
+pub fn schedules(nps: &str) -> Vec<PipeSize> {
+    let mut list: Vec<PipeSize> = nominal(nps)
+        .and_then(|n| TABLE.iter().find(|r| (r.nominal - n).abs() < 1e-6))
+        .map_or_else(Vec::new, |row| {
+            row.walls.iter().map(|w| size(row, w)).collect()
+        });
+    list.sort_by(|a, b| a.WT.total_cmp(&b.WT));
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inside_inch(nps: &str, schedule: &str) -> f64 {
+        lookup(nps, schedule).unwrap().ID() / INCH
+    }
+
+    #[test]
+    fn six_inch_schedule_40() {
+        let size = lookup("6", "40").unwrap();
+        assert_eq!((size.nps, size.schedule), ("6", "40"));
+        assert!((size.ID() / INCH - 6.065).abs() < 1e-9);
+        assert!((size.ID() - 0.154051).abs() < 1e-9); // [m]
+    }
+
+    #[test]
+    fn nps_forms() {
+        for nps in ["1-1/2", "1 1/2", "1.5", "1-1/2\"", " 1-1/2 "] {
+            assert!((inside_inch(nps, "40") - 1.610).abs() < 1e-9, "{}", nps);
+        }
+        assert!((inside_inch("3/4", "80") - 0.742).abs() < 1e-9);
+    }
+
+    #[test]
+    fn schedule_forms() {
+        for schedule in ["40", "SCH 40", "Sch40", "sch. 40", "Schedule 40"] {
+            assert!(
+                (inside_inch("6", schedule) - 6.065).abs() < 1e-9,
+                "{}",
+                schedule
+            );
+        }
+        // STD and schedule 40 coincide up to 10"
+        assert!((inside_inch("6", "STD") - 6.065).abs() < 1e-9);
+        assert!((inside_inch("6", "std") - 6.065).abs() < 1e-9);
+        assert!((inside_inch("6", "10S") - 6.357).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unknown_sizes() {
+        for (nps, schedule) in [("7", "40"), ("6", "99"), ("six", "40"), ("6", "")] {
+            assert_eq!(
+                lookup(nps, schedule),
+                Err(InputError::UnknownPipeSize {
+                    nps: nps.to_string(),
+                    schedule: schedule.to_string(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn schedule_listing() {
+        let forty = sizes("40");
+        assert!(forty.windows(2).all(|w| w[0].OD < w[1].OD));
+        assert!(forty.iter().any(|s| s.nps == "6"));
+        assert!(sizes("99").is_empty());
+        let six = schedules("6");
+        assert!(six.windows(2).all(|w| w[0].WT <= w[1].WT));
+    }
+}
@@ -0,0 +1,178 @@
+#![allow(non_snake_case)]
+
+// Line sizing: runs one line case through every standard size of a schedule and
+// selects the smallest size that meets the sizing criteria.
+//
+// Only the inside diameter changes from size to size; roughness, inclination, safety
+// factor and friction factor correlation are taken from the given input. Every size
+// is evaluated, so the report doubles as a comparison table with the reasons each
+// rejected size failed. A size whose no-slip mixture velocity or erosion factor already
+// exceeds max_velocity or max_Ef is rejected without running the model, which would
+// only extrapolate far outside its range there.
+
+use std::fmt;
+
+use crate::error::TwoPhaseError;
+use crate::input::LineInput;
+use crate::result::LineResult;
+use crate::schedule::{self, PipeSize};
+use crate::twoline::{FlowPattern, Regime, TwoPhaseLine};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizingCriteria {
+    pub schedule: &'static str, // pipe schedule to walk, e.g. "40", "STD", "10S"
+    // maximum frictional plus acceleration gradient, Pfric + Pacc, so the slug
+    // acceleration loss is included [kgf/cm^2/100m]
+    pub max_Pfric: Option<f64>,
+    pub max_Ef: Option<f64>,       // maximum erosion factor [-]
+    pub max_velocity: Option<f64>, // maximum mixture velocity [m/s]
+    // flow patterns not allowed, e.g. Intermittent; regimes without a flow pattern
+    // (HagedornBrownFlow, HomogeneousFlow) cannot be forbidden
+    pub forbidden: Vec<FlowPattern>,
+}
+
+impl Default for SizingCriteria {
+    fn default() -> Self {
+        SizingCriteria {
+            schedule: "40",
+            max_Pfric: None,
+            max_Ef: None,
+            max_velocity: None,
+            forbidden: Vec::new(),
+        }
+    }
+}
+
+// Why a size was rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rejection {
+    Pfric { value: f64, max: f64 }, // value is Pfric + Pacc
+    Ef { value: f64, max: f64 },
+    Velocity { value: f64, max: f64 },
+    Pattern(Regime),
+    // the model returned an error for this size
+    Failed(TwoPhaseError),
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::Pfric { value, max } => {
+                write!(f, "Pfric + Pacc {:.4} > {:.4} kgf/cm^2/100m", value, max)
+            }
+            Rejection::Ef { value, max } => write!(f, "Ef {:.4} > {:.4}", value, max),
+            Rejection::Velocity { value, max } => {
+                write!(f, "velocity {:.3} > {:.3} m/s", value, max)
+            }
+            Rejection::Pattern(regime) => write!(f, "{} is not allowed", regime),
+            Rejection::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+// One evaluated size
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeCase {
+    pub size: PipeSize,
+    pub result: Option<LineResult>, // None if the model failed or was not run
+    pub rejections: Vec<Rejection>, // empty if the size is acceptable
+}
+
+impl SizeCase {
+    pub fn accepted(&self) -> bool {
+        self.rejections.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sizing {
+    pub method: &'static str,
+    pub cases: Vec<SizeCase>, // every size of the schedule, smallest first
+}
+
+impl Sizing {
+    // the smallest acceptable size, None if no size meets the criteria
+    pub fn selected(&self) -> Option<&SizeCase> {
+        self.cases.iter().find(|c| c.accepted())
+    }
+}
+
+// velocity and erosion factor limits on the no-slip mixture, checked before the model
+fn screen(input: &LineInput, criteria: &SizingCriteria) -> Vec<Rejection> {
+    let f = &input.fluid;
+    let (UGS, ULS) = input.superficial_velocities();
+    let UM = UGS + ULS; // Mixture Velocity [m/s]
+    let LoNS = f.LoL * ULS / UM + f.LoG * UGS / UM; // no-slip density [kg/m^3]
+    let Ef = (LoNS * 0.062428) * (UM * 3.28084).powf(2.0) / 10000.0; // must transfer to imperial unit
+    let mut rejected = Vec::new();
+    if let Some(max) = criteria.max_Ef.filter(|&max| Ef > max) {
+        rejected.push(Rejection::Ef { value: Ef, max });
+    }
+    if let Some(max) = criteria.max_velocity.filter(|&max| UM > max) {
+        rejected.push(Rejection::Velocity { value: UM, max });
+    }
+    rejected
+}
+
+fn rejections(result: &LineResult, criteria: &SizingCriteria) -> Vec<Rejection> {
+    let mut rejected = Vec::new();
+    let loss = result.Pfric + result.Pacc;
+    if let Some(max) = criteria.max_Pfric.filter(|&max| loss > max) {
+        rejected.push(Rejection::Pfric { value: loss, max });
+    }
+    if let Some(max) = criteria.max_Ef.filter(|&max| result.Ef > max) {
+        rejected.push(Rejection::Ef {
+            value: result.Ef,
+            max,
+        });
+    }
+    if let Some(max) = criteria.max_velocity.filter(|&max| result.velocity > max) {
+        rejected.push(Rejection::Velocity {
+            value: result.velocity,
+            max,
+        });
+    }
+    if result
+        .regime
+        .pattern()
+        .is_some_and(|p| criteria.forbidden.contains(&p))
+    {
+        rejected.push(Rejection::Pattern(result.regime.clone()));
+    }
+    rejected
+}
+
+// Evaluates every size of criteria.schedule (none for an unknown schedule)
+pub fn size_line(line: &dyn TwoPhaseLine, input: &LineInput, criteria: &SizingCriteria) -> Sizing {
+    let cases = schedule::sizes(criteria.schedule)
+        .into_iter()
+        .map(|size| {
+            let mut trial = input.clone();
+            trial.pipe.ID = size.ID();
+            let screened = screen(&trial, criteria);
+            if !screened.is_empty() {
+                return SizeCase {
+                    size,
+                    result: None,
+                    rejections: screened,
+                };
+            }
+            match line.gradient(&trial) {
+                Ok(result) => SizeCase {
+                    size,
+                    rejections: rejections(&result, criteria),
+                    result: Some(result),
+                },
+                Err(e) => SizeCase {
+                    size,
+                    result: None,
+                    rejections: vec![Rejection::Failed(e)],
+                },
+            }
+        })
+        .collect();
+    Sizing {
+        method: line.method(),
+        cases,
+    }
+}
@@ -0,0 +1,436 @@
+// Bounded one-dimensional solvers shared by the flow models.
+//
+// Every method stops after `max_iter` iterations at the latest and reports failure as
+// TwoPhaseError::NonConvergence, so no model can loop forever on bad input. A converged
+// run returns the root together with the iterates it went through.
+
+use crate::error::TwoPhaseError;
+
+pub const DEFAULT_TOL: f64 = 1e-4; // allowable tolerance
+pub const DEFAULT_MAX_ITER: usize = 100; // trial number
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solver {
+    pub tol: f64,        // allowable tolerance (absolute, on the unknown)
+    pub max_iter: usize, // iteration cap
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Solver {
+            tol: DEFAULT_TOL,
+            max_iter: DEFAULT_MAX_ITER,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub root: f64,         // converged value
+    pub iterations: usize, // iterations used
+    pub residual: f64,     // last step size or bracket width
+    pub history: Vec<f64>, // iterate after each iteration
+}
+
+impl Solver {
+    pub fn new(tol: f64, max_iter: usize) -> Self {
+        Solver { tol, max_iter }
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    // Bisection on [a, b]; f(a) and f(b) must differ in sign.
+    // Converged when the bracket is no wider than tol, the last midpoint is returned, or
+    // on an exact zero of f. A NaN of f inside the bracket is a failure.
+    pub fn bisection<F>(
+        &self,
+        name: &'static str,
+        f: F,
+        a: f64,
+        b: f64,
+    ) -> Result<Solution, TwoPhaseError>
+    where
+        F: Fn(f64) -> f64,
+    {
+        let (mut a, mut b) = (a, b);
+        let mut fa = f(a);
+        let fb = f(b);
+        if (fa * fb).is_nan() || fa * fb > 0.0 {
+            return Err(TwoPhaseError::NotBracketed {
+                solver: name,
+                lower: a,
+                upper: b,
+            });
+        }
+        if fa == 0.0 || fb == 0.0 {
+            let root = if fa == 0.0 { a } else { b };
+            return Ok(Solution {
+                root,
+                iterations: 0,
+                residual: 0.0,
+                history: vec![root],
+            });
+        }
+        let mut history = Vec::new();
+        let mut width = (b - a).abs();
+        for n in 1..=self.max_iter {
+            let m = (a + b) / 2.0;
+            let fm = f(m);
+            if fm.is_nan() {
+                return Err(TwoPhaseError::NonConvergence {
+                    solver: name,
+                    iterations: n,
+                    residual: f64::NAN,
+                });
+            }
+            if fm == 0.0 {
+                history.push(m);
+                return Ok(Solution {
+                    root: m,
+                    iterations: n,
+                    residual: 0.0,
+                    history,
+                });
+            }
+            if fa * fm < 0.0 {
+                b = m;
+            } else {
+                a = m;
+                fa = fm;
+            }
+            history.push(m);
+            width = (b - a).abs();
+            if width <= self.tol {
+                return Ok(Solution {
+                    root: m,
+                    iterations: n,
+                    residual: width,
+                    history,
+                });
+            }
+        }
+        Err(self.non_convergence(name, width))
+    }
+
+    // Brent's method (inverse quadratic interpolation / secant / bisection) on [a, b];
+    // f(a) and f(b) must differ in sign.
+    pub fn brent<F>(
+        &self,
+        name: &'static str,
+        f: F,
+        a: f64,
+        b: f64,
+    ) -> Result<Solution, TwoPhaseError>
+    where
+        F: Fn(f64) -> f64,
+    {
+        let (mut a, mut b) = (a, b);
+        let (mut fa, mut fb) = (f(a), f(b));
+        if (fa * fb).is_nan() || fa * fb > 0.0 {
+            return Err(TwoPhaseError::NotBracketed {
+                solver: name,
+                lower: a,
+                upper: b,
+            });
+        }
+        let mut c = b;
+        let mut fc = fb;
+        let mut d = b - a;
+        let mut e = d;
+        let mut history = Vec::new();
+        let mut step = (b - a).abs();
+        for n in 1..=self.max_iter {
+            if (fb > 0.0 && fc > 0.0) || (fb < 0.0 && fc < 0.0) {
+                // keep the root between b and c
+                c = a;
+                fc = fa;
+                d = b - a;
+                e = d;
+            }
+            if fc.abs() < fb.abs() {
+                a = b;
+                b = c;
+                c = a;
+                fa = fb;
+                fb = fc;
+                fc = fa;
+            }
+            let tol1 = 2.0 * f64::EPSILON * b.abs() + 0.5 * self.tol;
+            let xm = 0.5 * (c - b);
+            step = xm.abs();
+            if step <= tol1 || fb == 0.0 {
+                history.push(b);
+                return Ok(Solution {
+                    root: b,
+                    iterations: n,
+                    residual: step,
+                    history,
+                });
+            }
+            if e.abs() >= tol1 && fa.abs() > fb.abs() {
+                // try interpolation
+                let s = fb / fa;
+                let (mut p, mut q);
+                if a == c {
+                    p = 2.0 * xm * s;
+                    q = 1.0 - s;
+                } else {
+                    let qq = fa / fc;
+                    let r = fb / fc;
+                    p = s * (2.0 * xm * qq * (qq - r) - (b - a) * (r - 1.0));
+                    q = (qq - 1.0) * (r - 1.0) * (s - 1.0);
+                }
+                if p > 0.0 {
+                    q = -q;
+                }
+                p = p.abs();
+                let min1 = 3.0 * xm * q - (tol1 * q).abs();
+                let min2 = (e * q).abs();
+                if 2.0 * p < min1.min(min2) {
+                    e = d;
+                    d = p / q;
+                } else {
+                    d = xm;
+                    e = d;
+                }
+            } else {
+                // fall back to bisection
+                d = xm;
+                e = d;
+            }
+            a = b;
+            fa = fb;
+            b += if d.abs() > tol1 { d } else { tol1.copysign(xm) };
+            fb = f(b);
+            history.push(b);
+        }
+        Err(self.non_convergence(name, step))
+    }
+
+    // Newton-Raphson from x0, kept inside [lower, upper]: a step that leaves the interval
+    // is replaced by the midpoint between the current point and the violated bound.
+    // `damping` (0, 1] relaxes the update, x += damping * (x_newton - x).
+    // Converged when |x_newton - x| <= tol; the relaxed point is returned.
+    pub fn newton<F, D>(
+        &self,
+        name: &'static str,
+        f: F,
+        df: D,
+        x0: f64,
+        (lower, upper): (f64, f64),
+        damping: f64,
+    ) -> Result<Solution, TwoPhaseError>
+    where
+        F: Fn(f64) -> f64,
+        D: Fn(f64) -> f64,
+    {
+        let mut x = x0;
+        let mut history = Vec::new();
+        let mut delta = f64::INFINITY;
+        for n in 1..=self.max_iter {
+            let mut xn = x - f(x) / df(x);
+            if !xn.is_finite() {
+                return Err(TwoPhaseError::NonConvergence {
+                    solver: name,
+                    iterations: n,
+                    residual: f64::NAN,
+                });
+            }
+            if xn < lower {
+                xn = (x + lower) / 2.0;
+            } else if xn > upper {
+                xn = (x + upper) / 2.0;
+            }
+            delta = (xn - x).abs();
+            x += damping * (xn - x);
+            history.push(x);
+            if delta <= self.tol {
+                return Ok(Solution {
+                    root: x,
+                    iterations: n,
+                    residual: delta,
+                    history,
+                });
+            }
+        }
+        Err(self.non_convergence(name, delta))
+    }
+
+    // Damped fixed-point iteration x += damping * (g(x) - x) from x0.
+    // Converged when |g(x) - x| <= tol; the relaxed point is returned.
+    pub fn fixed_point<G>(
+        &self,
+        name: &'static str,
+        g: G,
+        x0: f64,
+        damping: f64,
+    ) -> Result<Solution, TwoPhaseError>
+    where
+        G: Fn(f64) -> f64,
+    {
+        let mut x = x0;
+        let mut history = Vec::new();
+        let mut delta = f64::INFINITY;
+        for n in 1..=self.max_iter {
+            let xcal = g(x);
+            if !xcal.is_finite() {
+                return Err(TwoPhaseError::NonConvergence {
+                    solver: name,
+                    iterations: n,
+                    residual: f64::NAN,
+                });
+            }
+            delta = (xcal - x).abs();
+            x += damping * (xcal - x);
+            history.push(x);
+            if delta <= self.tol {
+                return Ok(Solution {
+                    root: x,
+                    iterations: n,
+                    residual: delta,
+                    history,
+                });
+            }
+        }
+        Err(self.non_convergence(name, delta))
+    }
+
+    fn non_convergence(&self, name: &'static str, residual: f64) -> TwoPhaseError {
+        TwoPhaseError::NonConvergence {
+            solver: name,
+            iterations: self.max_iter,
+            residual,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQRT2: f64 = std::f64::consts::SQRT_2;
+    const DOTTIE: f64 = 0.7390851332151607; // root of cos(x) = x
+
+    fn solver() -> Solver {
+        Solver::new(1e-10, 200)
+    }
+
+    #[test]
+    fn bisection_finds_sqrt2() {
+        let s = solver()
+            .bisection("x^2 - 2", |x| x * x - 2.0, 0.0, 2.0)
+            .unwrap();
+        assert!((s.root - SQRT2).abs() < 1e-9);
+        assert!(s.residual <= 1e-10);
+        assert_eq!(s.history.len(), s.iterations);
+    }
+
+    #[test]
+    fn bisection_returns_an_exact_zero() {
+        let s = solver().bisection("x - 1", |x| x - 1.0, 0.0, 2.0).unwrap();
+        assert_eq!((s.root, s.iterations), (1.0, 1));
+        let s = solver().bisection("x", |x| x, 0.0, 2.0).unwrap();
+        assert_eq!((s.root, s.iterations), (0.0, 0));
+    }
+
+    #[test]
+    fn bisection_fails_on_nan_inside_the_bracket() {
+        let f = |x: f64| if x == 1.0 { f64::NAN } else { x - 1.5 };
+        let e = solver().bisection("nan", f, 0.0, 2.0).unwrap_err();
+        assert!(matches!(
+            e,
+            TwoPhaseError::NonConvergence { iterations: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn brent_finds_sqrt2() {
+        let s = solver()
+            .brent("x^2 - 2", |x| x * x - 2.0, 0.0, 2.0)
+            .unwrap();
+        assert!((s.root - SQRT2).abs() < 1e-9);
+        // far fewer iterations than bisection
+        assert!(s.iterations < 15, "{} iterations", s.iterations);
+    }
+
+    #[test]
+    fn unbracketed_interval_is_reported() {
+        for result in [
+            solver().bisection("x^2 + 1", |x| x * x + 1.0, -1.0, 1.0),
+            solver().brent("x^2 + 1", |x| x * x + 1.0, -1.0, 1.0),
+        ] {
+            match result {
+                Err(TwoPhaseError::NotBracketed {
+                    solver,
+                    lower,
+                    upper,
+                }) => assert_eq!((solver, lower, upper), ("x^2 + 1", -1.0, 1.0)),
+                other => panic!("expected NotBracketed, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn newton_finds_sqrt2_inside_bounds() {
+        let s = solver()
+            .newton(
+                "x^2 - 2",
+                |x| x * x - 2.0,
+                |x| 2.0 * x,
+                1.0,
+                (0.0, 2.0),
+                1.0,
+            )
+            .unwrap();
+        assert!((s.root - SQRT2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn newton_fails_on_zero_derivative() {
+        let e = solver()
+            .newton(
+                "x^2 - 2",
+                |x| x * x - 2.0,
+                |x| 2.0 * x,
+                0.0,
+                (-2.0, 2.0),
+                1.0,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            e,
+            TwoPhaseError::NonConvergence { iterations: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn fixed_point_finds_dottie_number() {
+        let s = solver().fixed_point("cos", f64::cos, 0.5, 1.0).unwrap();
+        assert!((s.root - DOTTIE).abs() < 1e-9);
+        let damped = solver().fixed_point("cos", f64::cos, 0.5, 0.6).unwrap();
+        assert!((damped.root - DOTTIE).abs() < 1e-9);
+    }
+
+    #[test]
+    fn iteration_cap_is_reported() {
+        match Solver::new(1e-12, 5).fixed_point("cos", f64::cos, 0.5, 1.0) {
+            Err(TwoPhaseError::NonConvergence {
+                solver,
+                iterations,
+                residual,
+            }) => {
+                assert_eq!((solver, iterations), ("cos", 5));
+                assert!(residual > 1e-12);
+            }
+            other => panic!("expected NonConvergence, got {:?}", other),
+        }
+    }
+}
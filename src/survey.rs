@@ -0,0 +1,168 @@
+// Elevation profile of a terrain pipeline from survey points.
+//
+// A survey is a polyline of (distance, elevation) points [m], distance measured
+// horizontally from the first point and increasing. Each pair of points becomes one
+// straight Segment, its length along the pipe and its inclination taken from the
+// distance and elevation changes. CSV input holds one "distance, elevation" point per
+// line; blank lines and lines starting with '#' are skipped and the first line may be
+// a header.
+
+use std::io;
+use std::path::Path;
+
+use crate::input::{InputError, PipeGeometry};
+use crate::pipeline::{Pipeline, Segment};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Survey {
+    pub points: Vec<(f64, f64)>, // (distance, elevation) [m]
+}
+
+impl Survey {
+    // errors count points from 1 as the line, 0 for the survey as a whole
+    pub fn new(points: Vec<(f64, f64)>) -> Result<Self, InputError> {
+        if points.len() < 2 {
+            return Err(InputError::Survey {
+                line: 0,
+                reason: "at least two points are required",
+            });
+        }
+        if let Some(k) = points
+            .iter()
+            .position(|p| !(p.0.is_finite() && p.1.is_finite()))
+        {
+            return Err(InputError::Survey {
+                line: k + 1,
+                reason: "distance and elevation must be finite numbers",
+            });
+        }
+        if let Some(k) = points.windows(2).position(|w| w[1].0 <= w[0].0) {
+            return Err(InputError::Survey {
+                line: k + 2,
+                reason: "distance must increase from point to point",
+            });
+        }
+        Ok(Survey { points })
+    }
+
+    // points from CSV text, line numbers in errors count every line of the text
+    pub fn from_csv(text: &str) -> Result<Self, InputError> {
+        let (mut points, mut lines) = (Vec::new(), Vec::new());
+        let mut header = true; // the first line read may be a header
+        for (k, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let point = match fields[..] {
+                [distance, elevation] => distance
+                    .parse::<f64>()
+                    .ok()
+                    .zip(elevation.parse::<f64>().ok()),
+                _ => None,
+            };
+            match point {
+                Some(point) => {
+                    points.push(point);
+                    lines.push(k + 1);
+                }
+                None if header => {}
+                None => {
+                    return Err(InputError::Survey {
+                        line: k + 1,
+                        reason: "expected `distance, elevation`",
+                    })
+                }
+            }
+            header = false;
+        }
+        // Survey::new counts points, report the line of the text instead
+        Survey::new(points).map_err(|e| match e {
+            InputError::Survey { line, reason } => InputError::Survey {
+                line: lines.get(line.wrapping_sub(1)).copied().unwrap_or(line),
+                reason,
+            },
+            e => e,
+        })
+    }
+
+    // points from a CSV file; an unreadable point is reported as InvalidData
+    pub fn read_csv(path: impl AsRef<Path>) -> io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Survey::from_csv(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    // one segment per survey leg, all of the given pipe
+    pub fn segments(&self, pipe: &PipeGeometry) -> Result<Vec<Segment>, InputError> {
+        self.points
+            .windows(2)
+            .map(|w| {
+                let (run, rise) = (w[1].0 - w[0].0, w[1].1 - w[0].1);
+                Segment::with_rise(run.hypot(rise), pipe.clone(), rise)
+            })
+            .collect()
+    }
+
+    pub fn pipeline(&self, pipe: &PipeGeometry) -> Result<Pipeline, InputError> {
+        Ok(Pipeline::new(self.segments(pipe)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "\
+# terrain survey
+distance, elevation
+0, 100
+
+300, 100
+400, 200
+# crest
+500, 100
+";
+
+    #[test]
+    fn csv_points_skip_header_comments_and_blank_lines() {
+        let survey = Survey::from_csv(CSV).unwrap();
+        assert_eq!(
+            survey.points,
+            vec![(0.0, 100.0), (300.0, 100.0), (400.0, 200.0), (500.0, 100.0)]
+        );
+    }
+
+    #[test]
+    fn bad_rows_report_the_text_line() {
+        let bad = |text: &str, line: usize| match Survey::from_csv(text) {
+            Err(InputError::Survey { line: l, .. }) => assert_eq!(l, line, "{:?}", text),
+            other => panic!("expected a survey error, got {:?}", other),
+        };
+        bad("0, 0\n100, ten\n", 2); // not a number after the first line
+        bad("0, 0\n100, 1, 2\n", 2); // three fields
+        bad("distance, elevation\n0, 0\n# note\n0, 5\n", 4); // distance not increasing
+        bad("0, 0\nNaN, 5\n", 2); // not finite
+        bad("# only one point\n0, 0\n", 0);
+    }
+
+    #[test]
+    fn each_leg_takes_its_own_inclination() {
+        let pipe = PipeGeometry::new(0.2);
+        let segments = Survey::from_csv(CSV).unwrap().segments(&pipe).unwrap();
+        assert_eq!(segments.len(), 3);
+        let expected = [
+            (300.0, 0.0),                 // flat
+            (100.0 * 2f64.sqrt(), -45.0), // 45 degree uphill, -Up
+            (100.0 * 2f64.sqrt(), 45.0),  // 45 degree downhill, +Down
+        ];
+        for (segment, (length, degree)) in segments.iter().zip(expected) {
+            assert!((segment.length - length).abs() < 1e-9);
+            assert!((segment.pipe.degree - degree).abs() < 1e-9);
+            assert_eq!(segment.pipe.ID, 0.2);
+        }
+        let rise: f64 = segments.iter().map(Segment::rise).sum();
+        assert!(rise.abs() < 1e-9);
+        assert!((segments[1].rise() - 100.0).abs() < 1e-9);
+    }
+}
@@ -1,4 +1,10 @@
-#[derive(Debug)]
+use std::fmt;
+
+use crate::error::TwoPhaseError;
+use crate::input::LineInput;
+use crate::result::LineResult;
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Regime {
     // Vertical Up Flow Regime
     VerticalUpAnnularFlow(String),
@@ -16,12 +22,286 @@ pub enum Regime {
     VerticalDownAnnularFlow(String),
     VerticalDownSlugFlow(String),
     VerticalDownDispersedBubbleFlow(String),
+    // Beggs-Brill Flow Regime
+    BeggsBrillSegregatedFlow(String),
+    BeggsBrillTransitionFlow(String),
+    BeggsBrillIntermittentFlow(String),
+    BeggsBrillDistributedFlow(String),
+    // Hagedorn-Brown Flow Regime
+    HagedornBrownBubbleFlow(String),
+    HagedornBrownFlow(String),
+    // Duns-Ros Flow Regime
+    DunsRosBubbleFlow(String),
+    DunsRosSlugFlow(String),
+    DunsRosTransitionFlow(String),
+    DunsRosMistFlow(String),
+    // Homogeneous equilibrium model, any orientation
+    HomogeneousFlow(String),
     // Others
     NONE,
 }
+
+impl Regime {
+    pub fn name(&self) -> &str {
+        match self {
+            Regime::VerticalUpAnnularFlow(v)
+            | Regime::VerticalUpBubbleFlow(v)
+            | Regime::VerticalUpSlugAndChurnFlow(v)
+            | Regime::VerticalUpFinelyDispersedBubbleFlow(v)
+            | Regime::HorizontalStratifiedSmoothFlow(v)
+            | Regime::HorizontalStratifiedWavyFlow(v)
+            | Regime::HorizontalAnnularDispersedFlow(v)
+            | Regime::HorizontalElongatedBubbleFlow(v)
+            | Regime::HorizontalIntermittentSlugFlow(v)
+            | Regime::HorizontalDispersedBubbleFlow(v)
+            | Regime::VerticalDownAnnularFlow(v)
+            | Regime::VerticalDownSlugFlow(v)
+            | Regime::VerticalDownDispersedBubbleFlow(v)
+            | Regime::BeggsBrillSegregatedFlow(v)
+            | Regime::BeggsBrillTransitionFlow(v)
+            | Regime::BeggsBrillIntermittentFlow(v)
+            | Regime::BeggsBrillDistributedFlow(v)
+            | Regime::HagedornBrownBubbleFlow(v)
+            | Regime::HagedornBrownFlow(v)
+            | Regime::DunsRosBubbleFlow(v)
+            | Regime::DunsRosSlugFlow(v)
+            | Regime::DunsRosTransitionFlow(v)
+            | Regime::DunsRosMistFlow(v)
+            | Regime::HomogeneousFlow(v) => v,
+            Regime::NONE => "",
+        }
+    }
+}
+
+// Orientation-independent flow pattern family of a Regime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowPattern {
+    StratifiedSmooth,
+    StratifiedWavy,
+    Annular,
+    Intermittent, // elongated bubble, slug and churn
+    Bubble,
+    DispersedBubble,
+}
+
+impl Regime {
+    pub fn pattern(&self) -> Option<FlowPattern> {
+        match self {
+            Regime::HorizontalStratifiedSmoothFlow(..) => Some(FlowPattern::StratifiedSmooth),
+            Regime::HorizontalStratifiedWavyFlow(..) | Regime::BeggsBrillSegregatedFlow(..) => {
+                Some(FlowPattern::StratifiedWavy)
+            }
+            Regime::VerticalUpAnnularFlow(..)
+            | Regime::HorizontalAnnularDispersedFlow(..)
+            | Regime::VerticalDownAnnularFlow(..)
+            | Regime::DunsRosMistFlow(..) => Some(FlowPattern::Annular),
+            Regime::VerticalUpSlugAndChurnFlow(..)
+            | Regime::HorizontalElongatedBubbleFlow(..)
+            | Regime::HorizontalIntermittentSlugFlow(..)
+            | Regime::VerticalDownSlugFlow(..)
+            | Regime::BeggsBrillTransitionFlow(..)
+            | Regime::BeggsBrillIntermittentFlow(..)
+            | Regime::DunsRosSlugFlow(..)
+            | Regime::DunsRosTransitionFlow(..) => Some(FlowPattern::Intermittent),
+            Regime::VerticalUpBubbleFlow(..)
+            | Regime::HagedornBrownBubbleFlow(..)
+            | Regime::DunsRosBubbleFlow(..) => Some(FlowPattern::Bubble),
+            Regime::VerticalUpFinelyDispersedBubbleFlow(..)
+            | Regime::HorizontalDispersedBubbleFlow(..)
+            | Regime::VerticalDownDispersedBubbleFlow(..)
+            | Regime::BeggsBrillDistributedFlow(..) => Some(FlowPattern::DispersedBubble),
+            // Hagedorn-Brown outside Griffith bubble flow and the homogeneous model
+            // have no flow pattern
+            Regime::HagedornBrownFlow(..) | Regime::HomogeneousFlow(..) | Regime::NONE => None,
+        }
+    }
+}
+
+impl fmt::Display for Regime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// One transition criterion of a regime map, scaled so that the boundary lies at 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition {
+    pub name: &'static str,        // criterion label, e.g. "A" for Curve A
+    pub ratio: f64,                // criterion value / value on the boundary [-]
+    pub neighbour: Option<Regime>, // regime across this boundary alone, None if it changes nothing
+    pub geometric: bool, // fixed by the pipe geometry, not the flow rates; never the nearest boundary
+}
+
+impl Transition {
+    // normalized distance to the boundary, |ratio - 1| [-]
+    pub fn margin(&self) -> f64 {
+        (self.ratio - 1.0).abs()
+    }
+}
+
+// Outcome of a flow regime prediction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegimeReport {
+    pub regime: Regime,               // identified flow regime
+    pub method: &'static str,         // regime map that made the prediction
+    pub transitions: Vec<Transition>, // every criterion the map evaluated
+    pub margin: f64, // margin to the nearest flow boundary that changes the regime [-]
+    pub neighbour: Option<Regime>, // regime across that boundary
+}
+
+impl RegimeReport {
+    // Classify the ratios and find, for each one, the regime obtained by mirroring it
+    // across its boundary (ratio -> 2 - ratio) with the other ratios unchanged.
+    pub(crate) fn new<F>(method: &'static str, ratios: &[(&'static str, f64)], classify: F) -> Self
+    where
+        F: Fn(&[f64]) -> Regime,
+    {
+        let values: Vec<f64> = ratios.iter().map(|r| r.1).collect();
+        let regime = classify(&values);
+        let transitions: Vec<Transition> = ratios
+            .iter()
+            .enumerate()
+            .map(|(k, &(name, ratio))| {
+                let mut mirrored = values.clone();
+                mirrored[k] = 2.0 - ratio;
+                let other = classify(&mirrored);
+                Transition {
+                    name,
+                    ratio,
+                    neighbour: if other != regime { Some(other) } else { None },
+                    geometric: false,
+                }
+            })
+            .collect();
+        let mut report = RegimeReport {
+            regime,
+            method,
+            transitions,
+            margin: f64::INFINITY,
+            neighbour: None,
+        };
+        report.find_nearest();
+        report
+    }
+
+    // Mark the named criteria as geometric, e.g. a critical diameter ratio that no
+    // change of flow rate crosses, and leave them out of the margin and neighbour.
+    pub(crate) fn geometric(mut self, names: &[&str]) -> Self {
+        for t in self.transitions.iter_mut() {
+            t.geometric = names.contains(&t.name);
+        }
+        self.find_nearest();
+        self
+    }
+
+    fn find_nearest(&mut self) {
+        let nearest = self
+            .transitions
+            .iter()
+            .filter(|t| t.neighbour.is_some() && !t.geometric && !t.ratio.is_nan())
+            .min_by(|a, b| a.margin().total_cmp(&b.margin()));
+        self.margin = nearest.map_or(f64::INFINITY, |t| t.margin());
+        self.neighbour = nearest.and_then(|t| t.neighbour.clone());
+    }
+
+    pub fn ratio(&self, name: &str) -> Option<f64> {
+        self.transitions
+            .iter()
+            .find(|t| t.name == name)
+            .map(|t| t.ratio)
+    }
+}
+
+// A flow regime map together with the pressure-drop models it selects. Implementors
+// hold no line data, so one `Box<dyn TwoPhaseLine>` can be evaluated for any
+// number of LineInput cases.
 pub trait TwoPhaseLine {
-    fn unit_transfer(&mut self);
-    fn flow_regime(&mut self);
+    // name of the regime map and models
+    fn method(&self) -> &'static str;
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError>;
+
+    // pressure-drop model for an already identified regime
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError>;
+
+    fn gradient(&self, input: &LineInput) -> Result<LineResult, TwoPhaseError> {
+        let regime = self.regime(input)?.regime;
+        self.model(input, regime)
+    }
+}
+
+impl<T: TwoPhaseLine + ?Sized> TwoPhaseLine for &T {
+    fn method(&self) -> &'static str {
+        (**self).method()
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        (**self).regime(input)
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        (**self).model(input, regime)
+    }
+
+    fn gradient(&self, input: &LineInput) -> Result<LineResult, TwoPhaseError> {
+        (**self).gradient(input)
+    }
+}
+
+impl<T: TwoPhaseLine + ?Sized> TwoPhaseLine for Box<T> {
+    fn method(&self) -> &'static str {
+        (**self).method()
+    }
+
+    fn regime(&self, input: &LineInput) -> Result<RegimeReport, TwoPhaseError> {
+        (**self).regime(input)
+    }
+
+    fn model(&self, input: &LineInput, regime: Regime) -> Result<LineResult, TwoPhaseError> {
+        (**self).model(input, regime)
+    }
+
+    fn gradient(&self, input: &LineInput) -> Result<LineResult, TwoPhaseError> {
+        (**self).gradient(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // bubble below both ratios, slug once either crosses 1
+    fn classify(r: &[f64]) -> Regime {
+        if r[0] < 1.0 && r[1] < 1.0 {
+            Regime::VerticalUpBubbleFlow("Bubble".to_string())
+        } else {
+            Regime::VerticalUpSlugAndChurnFlow("Slug".to_string())
+        }
+    }
+
+    #[test]
+    fn nearest_boundary_sets_margin_and_neighbour() {
+        let report = RegimeReport::new("test", &[("flow", 0.7), ("Dcrit", 0.95)], classify);
+        assert!((report.margin - 0.05).abs() < 1e-12);
+        assert!(matches!(
+            report.neighbour,
+            Some(Regime::VerticalUpSlugAndChurnFlow(..))
+        ));
+    }
 
-    fn model_cal(&mut self);
+    #[test]
+    fn geometric_criteria_are_not_the_nearest_boundary() {
+        let report = RegimeReport::new("test", &[("flow", 0.7), ("Dcrit", 0.95)], classify)
+            .geometric(&["Dcrit"]);
+        assert!((report.margin - 0.3).abs() < 1e-12);
+        assert!(report.transitions[1].geometric && !report.transitions[0].geometric);
+        // the geometric criterion still decides the regime
+        let report = RegimeReport::new("test", &[("flow", 0.7), ("Dcrit", 1.2)], classify)
+            .geometric(&["Dcrit"]);
+        assert!(matches!(
+            report.regime,
+            Regime::VerticalUpSlugAndChurnFlow(..)
+        ));
+        assert!(report.margin.is_infinite() && report.neighbour.is_none());
+    }
 }
@@ -0,0 +1,206 @@
+// Unit systems for line input and output.
+//
+// The calculation models work in one fixed internal unit set:
+//   mass flow [kg/hr], density [kg/m^3], viscosity [kg/m-s], surface tension [kgf/m],
+//   diameter, roughness and length [m], velocity [m/s], pressure [kgf/cm^2],
+//   pressure gradient [kgf/cm^2/100m].
+// Values given in any UnitSystem are converted to this set once, when the LineInput
+// is built, and results can be converted back to any UnitSystem for reporting.
+// Inclination is always given in degree (Horizontal = 0, -Up / +Down).
+
+const KGF: f64 = 9.80665; // [N/kgf]
+const LB: f64 = 0.45359237; // [kg/lb]
+const FT: f64 = 0.3048; // [m/ft]
+pub(crate) const INCH: f64 = 0.0254; // [m/in]
+const PSI: f64 = 0.0703069578; // [kgf/cm^2 / psi]
+const DYNE_CM: f64 = 1.019716213E-4; // [kgf/m / (dyne/cm)]
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitSystem {
+    // kg/s, kg/m^3, Pa-s, N/m, m, m, m, m/s, Pa, Pa/m
+    SI,
+    // kg/hr, kg/m^3, cP, dyne/cm, mm, mm, m, m/s, kgf/cm^2, kgf/cm^2/100m
+    #[default]
+    MetricEngineering,
+    // lb/hr, lb/ft^3, cP, dyne/cm, in, in, ft, ft/s, psi, psi/100ft
+    USCustomary,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantity {
+    MassFlow,
+    Density,
+    Viscosity,
+    SurfaceTension,
+    Diameter,
+    Roughness,
+    Length,
+    Velocity,
+    Pressure,
+    PressureGradient,
+}
+
+impl UnitSystem {
+    // multiply a value in this unit system by this factor to get the internal unit
+    fn factor(self, q: Quantity) -> f64 {
+        match self {
+            UnitSystem::SI => match q {
+                Quantity::MassFlow => 3600.0,
+                Quantity::Density => 1.0,
+                Quantity::Viscosity => 1.0,
+                Quantity::SurfaceTension => 1.0 / KGF,
+                Quantity::Diameter => 1.0,
+                Quantity::Roughness => 1.0,
+                Quantity::Length => 1.0,
+                Quantity::Velocity => 1.0,
+                Quantity::Pressure => 1.0 / (KGF * 10000.0),
+                Quantity::PressureGradient => 100.0 / (KGF * 10000.0),
+            },
+            UnitSystem::MetricEngineering => match q {
+                Quantity::MassFlow => 1.0,
+                Quantity::Density => 1.0,
+                Quantity::Viscosity => 0.001,
+                Quantity::SurfaceTension => DYNE_CM,
+                Quantity::Diameter => 0.001,
+                Quantity::Roughness => 0.001,
+                Quantity::Length => 1.0,
+                Quantity::Velocity => 1.0,
+                Quantity::Pressure => 1.0,
+                Quantity::PressureGradient => 1.0,
+            },
+            UnitSystem::USCustomary => match q {
+                Quantity::MassFlow => LB,
+                Quantity::Density => LB / FT.powi(3),
+                Quantity::Viscosity => 0.001,
+                Quantity::SurfaceTension => DYNE_CM,
+                Quantity::Diameter => INCH,
+                Quantity::Roughness => INCH,
+                Quantity::Length => FT,
+                Quantity::Velocity => FT,
+                Quantity::Pressure => PSI,
+                Quantity::PressureGradient => PSI / FT,
+            },
+        }
+    }
+
+    // value in this unit system -> internal unit
+    pub fn to_internal(self, q: Quantity, value: f64) -> f64 {
+        value * self.factor(q)
+    }
+
+    // internal unit -> value in this unit system
+    pub fn from_internal(self, q: Quantity, value: f64) -> f64 {
+        value / self.factor(q)
+    }
+
+    // value in `from` unit system -> value in this unit system
+    pub fn convert(self, q: Quantity, value: f64, from: UnitSystem) -> f64 {
+        self.from_internal(q, from.to_internal(q, value))
+    }
+
+    pub fn label(self, q: Quantity) -> &'static str {
+        match self {
+            UnitSystem::SI => match q {
+                Quantity::MassFlow => "kg/s",
+                Quantity::Density => "kg/m^3",
+                Quantity::Viscosity => "Pa-s",
+                Quantity::SurfaceTension => "N/m",
+                Quantity::Diameter => "m",
+                Quantity::Roughness => "m",
+                Quantity::Length => "m",
+                Quantity::Velocity => "m/s",
+                Quantity::Pressure => "Pa",
+                Quantity::PressureGradient => "Pa/m",
+            },
+            UnitSystem::MetricEngineering => match q {
+                Quantity::MassFlow => "kg/hr",
+                Quantity::Density => "kg/m^3",
+                Quantity::Viscosity => "cP",
+                Quantity::SurfaceTension => "dyne/cm",
+                Quantity::Diameter => "mm",
+                Quantity::Roughness => "mm",
+                Quantity::Length => "m",
+                Quantity::Velocity => "m/s",
+                Quantity::Pressure => "kgf/cm^2",
+                Quantity::PressureGradient => "kgf/cm^2/100m",
+            },
+            UnitSystem::USCustomary => match q {
+                Quantity::MassFlow => "lb/hr",
+                Quantity::Density => "lb/ft^3",
+                Quantity::Viscosity => "cP",
+                Quantity::SurfaceTension => "dyne/cm",
+                Quantity::Diameter => "in",
+                Quantity::Roughness => "in",
+                Quantity::Length => "ft",
+                Quantity::Velocity => "ft/s",
+                Quantity::Pressure => "psi",
+                Quantity::PressureGradient => "psi/100ft",
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SYSTEMS: [UnitSystem; 3] = [
+        UnitSystem::SI,
+        UnitSystem::MetricEngineering,
+        UnitSystem::USCustomary,
+    ];
+    const QUANTITIES: [Quantity; 10] = [
+        Quantity::MassFlow,
+        Quantity::Density,
+        Quantity::Viscosity,
+        Quantity::SurfaceTension,
+        Quantity::Diameter,
+        Quantity::Roughness,
+        Quantity::Length,
+        Quantity::Velocity,
+        Quantity::Pressure,
+        Quantity::PressureGradient,
+    ];
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() <= 1e-9 * b.abs().max(1.0), "{} != {}", a, b);
+    }
+
+    #[test]
+    fn round_trip_through_internal_units() {
+        for u in SYSTEMS {
+            for q in QUANTITIES {
+                assert_close(u.from_internal(q, u.to_internal(q, 123.456)), 123.456);
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_between_systems() {
+        for from in SYSTEMS {
+            for to in SYSTEMS {
+                for q in QUANTITIES {
+                    let there = to.convert(q, 42.0, from);
+                    assert_close(from.convert(q, there, to), 42.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn known_factors() {
+        let si = UnitSystem::SI;
+        let us = UnitSystem::USCustomary;
+        let me = UnitSystem::MetricEngineering;
+        assert_close(si.to_internal(Quantity::MassFlow, 1.0), 3600.0); // kg/s -> kg/hr
+        assert_close(us.to_internal(Quantity::Diameter, 6.065), 0.154051); // in -> m
+        assert_close(me.to_internal(Quantity::Viscosity, 1.0), 0.001); // cP -> kg/m-s
+        assert_close(si.to_internal(Quantity::Pressure, 98066.5), 1.0); // Pa -> kgf/cm^2
+        assert_close(
+            me.convert(Quantity::Pressure, 1.0, UnitSystem::USCustomary),
+            0.0703069578,
+        ); // psi -> kgf/cm^2
+        assert_close(us.to_internal(Quantity::Density, 1.0), 16.018463373960138);
+        // lb/ft^3 -> kg/m^3
+    }
+}